@@ -1,8 +1,31 @@
-use sec::sec_state_machine::ingestion::retrieval::Retrieval;
+use clap::Parser;
+use sec::sec_state_machine::ingestion::retrieval::{
+    retrieval_context::{config::DEFAULT_CIK, RetrievalContextUpdaterBuilder},
+    retrieval_data::retrieval_input_data::CIK,
+    Retrieval, RetrievalContext, RetrievalInputData,
+};
 use state_maschine::prelude::*;
 
+/// Retrieves the XBRL company facts of a company from the SEC API.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Cli {
+    /// Central Index Key (CIK) of the company, e.g. `1067983`. Shorter CIKs are zero-padded.
+    #[arg(default_value = DEFAULT_CIK, value_parser = parse_cik)]
+    cik: CIK,
+}
+
+/// Parses the CIK argument, adding a hint on how to fix invalid input to the error clap reports.
+fn parse_cik(raw_cik: &str) -> Result<CIK, String> {
+    CIK::try_new(raw_cik).map_err(|err| format!("{err} {}", err.suggestion()))
+}
+
 fn main() {
-    let mut retrieval_state = Retrieval::default();
+    let cli = Cli::parse();
+
+    let mut context = RetrievalContext::default();
+    context.update_context(RetrievalContextUpdaterBuilder::new().cik(&cli.cik).build());
+    let mut retrieval_state = Retrieval::new(RetrievalInputData::new(&cli.cik), context);
 
     println!("\n=======================================================");
     println!("Initial Retrieval state:");
@@ -14,3 +37,62 @@ fn main() {
     println!("Retrieval state after querying SEC API with CIK:");
     println!("{:.500}...", retrieval_state.to_string().as_str());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Cli, CIK, DEFAULT_CIK};
+    use clap::{error::ErrorKind, Parser};
+
+    #[test]
+    fn should_use_default_cik_when_no_argument_is_passed() {
+        let expected_result = &CIK::new(DEFAULT_CIK);
+
+        let result = Cli::try_parse_from(["ingestion"]).expect("Parsing should succeed.");
+
+        assert_eq!(&result.cik, expected_result);
+    }
+
+    #[test]
+    fn should_pad_cik_argument_when_passed_valid_cik_with_less_than_ten_digits() {
+        let expected_result = "0000320193";
+
+        let result = Cli::try_parse_from(["ingestion", "320193"]).expect("Parsing should succeed.");
+
+        assert_eq!(result.cik.value(), expected_result);
+    }
+
+    #[test]
+    fn should_return_value_validation_error_with_hint_when_cik_argument_contains_non_numeric_chars()
+    {
+        let expected_result = ErrorKind::ValueValidation;
+        let expected_hint = "Remove the letters: CIKs are numeric, e.g. '320193'.";
+
+        let result =
+            Cli::try_parse_from(["ingestion", "12345abcd!"]).expect_err("Parsing should fail.");
+
+        assert_eq!(result.kind(), expected_result);
+        assert!(result.to_string().contains(expected_hint));
+    }
+
+    #[test]
+    fn should_return_value_validation_error_when_cik_argument_is_longer_than_ten_digits() {
+        let expected_result = ErrorKind::ValueValidation;
+
+        let result = Cli::try_parse_from(["ingestion", "12345678901"])
+            .expect_err("Parsing should fail.")
+            .kind();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_unknown_argument_error_when_passed_unknown_flag() {
+        let expected_result = ErrorKind::UnknownArgument;
+
+        let result = Cli::try_parse_from(["ingestion", "--unknown"])
+            .expect_err("Parsing should fail.")
+            .kind();
+
+        assert_eq!(result, expected_result);
+    }
+}
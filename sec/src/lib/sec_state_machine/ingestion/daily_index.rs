@@ -0,0 +1,215 @@
+use crate::sec_state_machine::ingestion::retrieval::retrieval_data::retrieval_input_data::{
+    FormType, InvalidCikFormat, CIK,
+};
+use std::{fmt, str::FromStr};
+
+/// Base URL of the SEC daily index files.
+pub const DAILY_INDEX_BASE_URL: &str = "https://www.sec.gov/Archives/edgar/daily-index/";
+
+const HEADER: &str = "CIK|Company Name|Form Type|Date Filed|File Name";
+const SEPARATOR: char = '|';
+const FIELD_COUNT: usize = 5;
+
+/// Error returned when a daily index file cannot be parsed.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub enum DailyIndexError {
+    /// The `CIK|Company Name|...` column header preceding the entries was not found.
+    MissingHeader,
+    /// An entry line does not consist of exactly five `|`-separated fields.
+    MalformedLine { line_number: usize, line: String },
+    /// The CIK field of an entry line is not a valid CIK.
+    InvalidCik {
+        line_number: usize,
+        source: InvalidCikFormat,
+    },
+}
+
+impl fmt::Display for DailyIndexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::MissingHeader => write!(
+                f,
+                "Invalid daily index: column header '{HEADER}' not found."
+            ),
+            Self::MalformedLine { line_number, line } => write!(
+                f,
+                "Invalid daily index: line {line_number} must have {FIELD_COUNT} '{SEPARATOR}'-separated fields. Got: '{line}'"
+            ),
+            Self::InvalidCik {
+                line_number,
+                source,
+            } => write!(f, "Invalid daily index: line {line_number}: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for DailyIndexError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidCik { source, .. } => Some(source),
+            Self::MissingHeader | Self::MalformedLine { .. } => None,
+        }
+    }
+}
+
+/// A single filing listed in a daily index file.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub struct DailyIndexEntry {
+    pub cik: CIK,
+    pub company: String,
+    pub form: FormType,
+    /// Filing date as given in the index, e.g. `20231005`.
+    pub date: String,
+    /// Path of the filing relative to `https://www.sec.gov/Archives/`.
+    pub filename: String,
+}
+
+/// The parsed contents of a daily `master.idx` file, listing the filings disseminated on one day.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub struct DailyIndex {
+    entries: Vec<DailyIndexEntry>,
+}
+
+impl DailyIndex {
+    #[must_use]
+    pub const fn entries(&self) -> &Vec<DailyIndexEntry> {
+        &self.entries
+    }
+}
+
+impl FromStr for DailyIndex {
+    type Err = DailyIndexError;
+
+    /// Parses a `master.idx` file. The preamble before the column header and the dashed line
+    /// following it are skipped; blank lines are ignored.
+    fn from_str(index: &str) -> Result<Self, Self::Err> {
+        let mut lines = index.lines().enumerate();
+
+        lines
+            .by_ref()
+            .find(|(_, line)| line.trim() == HEADER)
+            .ok_or(DailyIndexError::MissingHeader)?;
+
+        let entries = lines
+            .filter(|(_, line)| {
+                let line = line.trim();
+                !line.is_empty() && !line.chars().all(|c| c == '-')
+            })
+            .map(|(index, line)| {
+                let line_number = index + 1;
+                let fields: Vec<&str> = line.split(SEPARATOR).map(str::trim).collect();
+                let [cik, company, form, date, filename] = fields[..] else {
+                    return Err(DailyIndexError::MalformedLine {
+                        line_number,
+                        line: line.to_string(),
+                    });
+                };
+                let Ok(form) = form.parse::<FormType>();
+
+                Ok(DailyIndexEntry {
+                    cik: CIK::try_new(cik).map_err(|source| DailyIndexError::InvalidCik {
+                        line_number,
+                        source,
+                    })?,
+                    company: company.to_string(),
+                    form,
+                    date: date.to_string(),
+                    filename: filename.to_string(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { entries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INDEX: &str = "\
+Description:           Daily Index of EDGAR Dissemination Feed by Company Name
+Last Data Received:    October 5, 2023
+Comments:              webmaster@sec.gov
+Anonymous FTP:         ftp://ftp.sec.gov/edgar/
+
+
+
+
+CIK|Company Name|Form Type|Date Filed|File Name
+--------------------------------------------------------------------------------
+1000045|NICHOLAS FINANCIAL INC|8-K|20231005|edgar/data/1000045/0000950170-23-053486.txt
+320193|Apple Inc.|10-K/A|20231005|edgar/data/320193/0000320193-23-000106.txt
+1067983|BERKSHIRE HATHAWAY INC|SC 13G|20231005|edgar/data/1067983/0000950123-23-009100.txt
+";
+
+    #[test]
+    fn should_parse_all_entries_when_passed_index_with_preamble() {
+        let expected_result = 3;
+
+        let result = SAMPLE_INDEX.parse::<DailyIndex>().unwrap().entries().len();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_parse_entry_fields_when_passed_valid_entry_line() {
+        let expected_result = &DailyIndexEntry {
+            cik: CIK::new("320193"),
+            company: "Apple Inc.".to_string(),
+            form: FormType::TenK { amended: true },
+            date: "20231005".to_string(),
+            filename: "edgar/data/320193/0000320193-23-000106.txt".to_string(),
+        };
+
+        let index = SAMPLE_INDEX.parse::<DailyIndex>().unwrap();
+        let result = &index.entries()[1];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_empty_index_when_header_is_not_followed_by_entries() {
+        let index = format!("{HEADER}\n{}\n", "-".repeat(80));
+
+        let result = index.parse::<DailyIndex>().unwrap();
+
+        assert!(result.entries().is_empty());
+    }
+
+    #[test]
+    fn should_return_missing_header_error_when_column_header_is_absent() {
+        let expected_result = Err(DailyIndexError::MissingHeader);
+
+        let result = "1000045|NICHOLAS FINANCIAL INC|8-K|20231005|edgar/data/1000045/0000950170-23-053486.txt"
+            .parse::<DailyIndex>();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_malformed_line_error_when_entry_has_missing_fields() {
+        let index = format!("{HEADER}\n1000045|NICHOLAS FINANCIAL INC|8-K\n");
+
+        let expected_result = Err(DailyIndexError::MalformedLine {
+            line_number: 2,
+            line: "1000045|NICHOLAS FINANCIAL INC|8-K".to_string(),
+        });
+
+        let result = index.parse::<DailyIndex>();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_invalid_cik_error_when_entry_has_non_numeric_cik() {
+        let index = format!("{HEADER}\nABC|NICHOLAS FINANCIAL INC|8-K|20231005|edgar/data/x.txt\n");
+
+        let result = index.parse::<DailyIndex>();
+
+        assert!(matches!(
+            result,
+            Err(DailyIndexError::InvalidCik { line_number: 2, .. })
+        ));
+    }
+}
@@ -0,0 +1,189 @@
+use crate::sec_state_machine::ingestion::retrieval::retrieval_data::retrieval_input_data::{
+    AccessionNumber, CIK,
+};
+use state_maschine::prelude::*;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub struct DocumentRetrievalInputData {
+    cik: CIK,
+    accession_number: AccessionNumber,
+    primary_document: String,
+}
+
+impl DocumentRetrievalInputData {
+    pub fn new(
+        cik: CIK,
+        accession_number: AccessionNumber,
+        primary_document: &(impl ToString + ?Sized),
+    ) -> Self {
+        Self {
+            cik,
+            accession_number,
+            primary_document: primary_document.to_string(),
+        }
+    }
+
+    #[must_use]
+    pub const fn cik(&self) -> &CIK {
+        &self.cik
+    }
+
+    #[must_use]
+    pub const fn accession_number(&self) -> &AccessionNumber {
+        &self.accession_number
+    }
+
+    /// Returns the file name of the document within the filing, e.g. `aapl-20230930.htm`.
+    #[must_use]
+    pub const fn primary_document(&self) -> &String {
+        &self.primary_document
+    }
+}
+
+impl fmt::Display for DocumentRetrievalInputData {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "\tCIK: {}\n\tAccession Number: {}\n\tPrimary Document: {}",
+            self.cik, self.accession_number, self.primary_document
+        )
+    }
+}
+
+impl StateData for DocumentRetrievalInputData {
+    type UpdateType = DocumentRetrievalInputDataUpdater;
+    fn get_state(&self) -> &Self {
+        self
+    }
+
+    fn update_state(&mut self, updates: Self::UpdateType) {
+        if let Some(value) = updates.cik {
+            self.cik = value;
+        }
+        if let Some(value) = updates.accession_number {
+            self.accession_number = value;
+        }
+        if let Some(value) = updates.primary_document {
+            self.primary_document = value;
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub struct DocumentRetrievalInputDataUpdater {
+    pub cik: Option<CIK>,
+    pub accession_number: Option<AccessionNumber>,
+    pub primary_document: Option<String>,
+}
+
+pub struct DocumentRetrievalInputDataUpdaterBuilder {
+    cik: Option<CIK>,
+    accession_number: Option<AccessionNumber>,
+    primary_document: Option<String>,
+}
+impl DocumentRetrievalInputDataUpdaterBuilder {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            cik: None,
+            accession_number: None,
+            primary_document: None,
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn cik(mut self, cik: CIK) -> Self {
+        self.cik = Some(cik);
+        self
+    }
+
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn accession_number(mut self, accession_number: AccessionNumber) -> Self {
+        self.accession_number = Some(accession_number);
+        self
+    }
+
+    #[must_use]
+    pub fn primary_document(mut self, primary_document: &(impl ToString + ?Sized)) -> Self {
+        self.primary_document = Some(primary_document.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> DocumentRetrievalInputDataUpdater {
+        DocumentRetrievalInputDataUpdater {
+            cik: self.cik,
+            accession_number: self.accession_number,
+            primary_document: self.primary_document,
+        }
+    }
+}
+
+impl Default for DocumentRetrievalInputDataUpdaterBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AccessionNumber, DocumentRetrievalInputData, DocumentRetrievalInputDataUpdaterBuilder, CIK,
+    };
+    use state_maschine::prelude::*;
+
+    fn sample_input_data() -> DocumentRetrievalInputData {
+        DocumentRetrievalInputData::new(
+            CIK::new("320193"),
+            "0000320193-23-000106".parse().unwrap(),
+            "aapl-20230930.htm",
+        )
+    }
+
+    #[test]
+    fn should_update_primary_document_when_update_contains_new_primary_document() {
+        let mut state_data = sample_input_data();
+        let update = DocumentRetrievalInputDataUpdaterBuilder::new()
+            .primary_document("aapl-20230930_htm.xml")
+            .build();
+
+        let expected_result = "aapl-20230930_htm.xml";
+
+        state_data.update_state(update);
+        let result = state_data.get_state().primary_document();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_update_accession_number_when_update_contains_new_accession_number() {
+        let mut state_data = sample_input_data();
+        let accession_number: AccessionNumber = "0000320193-22-000108".parse().unwrap();
+        let update = DocumentRetrievalInputDataUpdaterBuilder::new()
+            .accession_number(accession_number.clone())
+            .build();
+
+        let expected_result = &accession_number;
+
+        state_data.update_state(update);
+        let result = state_data.get_state().accession_number();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_leave_state_data_unchanged_when_empty_update() {
+        let mut state_data = sample_input_data();
+        let empty_update = DocumentRetrievalInputDataUpdaterBuilder::default().build();
+
+        let expected_result = &sample_input_data();
+
+        state_data.update_state(empty_update);
+        let result = state_data.get_state();
+
+        assert_eq!(result, expected_result);
+    }
+}
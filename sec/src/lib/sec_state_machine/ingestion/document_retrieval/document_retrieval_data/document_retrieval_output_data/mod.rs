@@ -0,0 +1,120 @@
+use state_maschine::prelude::*;
+use std::fmt;
+
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub struct DocumentRetrievalOutputData {
+    document: Vec<u8>,
+}
+
+impl DocumentRetrievalOutputData {
+    #[must_use]
+    pub fn new(document: &[u8]) -> Self {
+        Self {
+            document: document.to_vec(),
+        }
+    }
+
+    /// Returns the raw bytes of the retrieved document.
+    #[must_use]
+    pub const fn document(&self) -> &Vec<u8> {
+        &self.document
+    }
+}
+
+impl fmt::Display for DocumentRetrievalOutputData {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "\tDocument: {} bytes", self.document.len())
+    }
+}
+
+impl StateData for DocumentRetrievalOutputData {
+    type UpdateType = DocumentRetrievalOutputDataUpdater;
+    fn get_state(&self) -> &Self {
+        self
+    }
+
+    fn update_state(&mut self, updates: Self::UpdateType) {
+        if let Some(value) = updates.document {
+            self.document = value;
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub struct DocumentRetrievalOutputDataUpdater {
+    pub document: Option<Vec<u8>>,
+}
+
+pub struct DocumentRetrievalOutputDataUpdaterBuilder {
+    document: Option<Vec<u8>>,
+}
+impl DocumentRetrievalOutputDataUpdaterBuilder {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { document: None }
+    }
+
+    #[must_use]
+    pub fn document(mut self, document: &[u8]) -> Self {
+        self.document = Some(document.to_vec());
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> DocumentRetrievalOutputDataUpdater {
+        DocumentRetrievalOutputDataUpdater {
+            document: self.document,
+        }
+    }
+}
+
+impl Default for DocumentRetrievalOutputDataUpdaterBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DocumentRetrievalOutputData, DocumentRetrievalOutputDataUpdaterBuilder};
+    use state_maschine::prelude::*;
+
+    #[test]
+    fn should_update_document_to_specified_bytes_when_update_contains_specified_bytes() {
+        let mut state_data = DocumentRetrievalOutputData::default();
+        let update = DocumentRetrievalOutputDataUpdaterBuilder::new()
+            .document(b"<html></html>")
+            .build();
+
+        let expected_result = &DocumentRetrievalOutputData::new(b"<html></html>");
+
+        state_data.update_state(update);
+        let result = state_data.get_state();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_leave_state_data_unchanged_when_empty_update() {
+        let mut state_data = DocumentRetrievalOutputData::default();
+        let empty_update = DocumentRetrievalOutputDataUpdaterBuilder::default().build();
+
+        let expected_result = &DocumentRetrievalOutputData::default();
+
+        state_data.update_state(empty_update);
+        let result = state_data.get_state();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_show_document_size_instead_of_content_when_displayed() {
+        let state_data = DocumentRetrievalOutputData::new(b"<html></html>");
+
+        let expected_result = "\tDocument: 13 bytes";
+
+        let result = state_data.to_string();
+
+        assert_eq!(result, expected_result);
+    }
+}
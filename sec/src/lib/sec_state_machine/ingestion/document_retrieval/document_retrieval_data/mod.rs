@@ -0,0 +1,5 @@
+pub mod document_retrieval_input_data;
+pub mod document_retrieval_output_data;
+
+pub use document_retrieval_input_data::DocumentRetrievalInputData;
+pub use document_retrieval_output_data::DocumentRetrievalOutputData;
@@ -0,0 +1,366 @@
+use crate::sec_state_machine::ingestion::retrieval::{
+    is_truncated,
+    retrieval_context::{
+        self, get_sec_user_client, RetrievalContext, RetrievalContextUpdaterBuilder,
+    },
+};
+use crate::sec_state_machine::ingestion::state_id::StateId;
+use document_retrieval_data::document_retrieval_output_data::DocumentRetrievalOutputDataUpdaterBuilder;
+use reqwest::{header::USER_AGENT, StatusCode};
+use state_maschine::prelude::*;
+use std::{
+    fmt,
+    time::{Instant, SystemTime},
+};
+
+pub mod document_retrieval_data;
+
+pub use document_retrieval_data::{DocumentRetrievalInputData, DocumentRetrievalOutputData};
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub struct DocumentRetrieval {
+    input: DocumentRetrievalInputData,
+    output: Option<DocumentRetrievalOutputData>,
+    context: RetrievalContext,
+}
+
+impl DocumentRetrieval {
+    /// The name returned by `get_state_name`.
+    pub const STATE_NAME: &'static str = "Document Retrieval";
+    /// The typed identifier of this state.
+    pub const STATE_ID: StateId = StateId::DocumentRetrieval;
+
+    #[must_use]
+    pub const fn new(input: DocumentRetrievalInputData, context: RetrievalContext) -> Self {
+        Self {
+            input,
+            output: None,
+            context,
+        }
+    }
+
+    /// Returns the EDGAR archive URL of the document this state downloads when computing its output.
+    ///
+    /// EDGAR archive paths use the CIK without leading zeros and the accession number without dashes.
+    #[must_use]
+    pub fn target_url(&self) -> String {
+        let input = self.get_input_data();
+        let cik = input.cik().value().trim_start_matches('0');
+        let cik = if cik.is_empty() { "0" } else { cik };
+
+        format!(
+            "https://www.sec.gov/Archives/edgar/data/{cik}/{}/{}",
+            input.accession_number().without_dashes(),
+            input.primary_document()
+        )
+    }
+
+    /// Downloads the document from `url`, or serves it from the cache, and stores it as the output.
+    fn retrieve_from(&mut self, url: &str) {
+        // Serve the document from the cache without sending a request, if it holds a live entry
+        if let Some(cached) = self.context.cache().and_then(|cache| cache.get(url)) {
+            let context_updater = RetrievalContextUpdaterBuilder::new()
+                .status(retrieval_context::Status::PostRetrieval)
+                .build();
+
+            self.context.update_context(context_updater);
+
+            let output_updater = DocumentRetrievalOutputDataUpdaterBuilder::new()
+                .document(&cached.body)
+                .build();
+
+            self.output
+                .get_or_insert_with(DocumentRetrievalOutputData::default)
+                .update_state(output_updater);
+            return;
+        }
+
+        let client_result = get_sec_user_client();
+        match client_result {
+            Ok(client) => {
+                let start = Instant::now();
+                let response_result = client
+                    .get(url)
+                    .header(USER_AGENT, self.context.next_user_agent())
+                    .send();
+                let fetched_at = SystemTime::now();
+
+                self.context
+                    .log_request(url, &response_result, start.elapsed());
+
+                match response_result.and_then(|response| {
+                    if response.status() == StatusCode::NOT_FOUND {
+                        Ok(None)
+                    } else {
+                        response.error_for_status().map(Some)
+                    }
+                }) {
+                    Ok(Some(response)) => {
+                        match (response.content_length(), self.context.read_body(response)) {
+                            (content_length, Ok(Some(document))) => {
+                                // Never cache a truncated document, which is only accepted if verification is off
+                                if let Some(cache) = self
+                                    .context
+                                    .cache()
+                                    .filter(|_| !is_truncated(content_length, document.len()))
+                                {
+                                    cache.put(url, &document, fetched_at);
+                                }
+
+                                let context_updater = RetrievalContextUpdaterBuilder::new()
+                                    .status(retrieval_context::Status::PostRetrieval)
+                                    .build();
+
+                                self.context.update_context(context_updater);
+
+                                let output_updater =
+                                    DocumentRetrievalOutputDataUpdaterBuilder::new()
+                                        .document(&document)
+                                        .build();
+
+                                self.output
+                                    .get_or_insert_with(DocumentRetrievalOutputData::default)
+                                    .update_state(output_updater);
+                            }
+                            (_, Ok(None)) => {
+                                eprintln!(
+                                "Document too large: '{}' of filing '{}' for CIK '{}' exceeds the limit of {} bytes.",
+                                self.input.primary_document(),
+                                self.input.accession_number(),
+                                self.input.cik(),
+                                self.context.max_body_bytes().unwrap_or_default(),
+                            );
+                            }
+                            (_, Err(err)) => {
+                                eprintln!(
+                                "Failed to read document '{}' of filing '{}' for CIK '{}': {err}",
+                                self.input.primary_document(),
+                                self.input.accession_number(),
+                                self.input.cik(),
+                            );
+                            }
+                        }
+                    }
+                    Ok(None) => {
+                        eprintln!(
+                            "Document not found: '{}' of filing '{}' for CIK '{}' does not exist.",
+                            self.input.primary_document(),
+                            self.input.accession_number(),
+                            self.input.cik(),
+                        );
+                    }
+                    Err(err) => {
+                        eprintln!(
+                            "Failed to retrieve document '{}' of filing '{}' for CIK '{}': {err}",
+                            self.input.primary_document(),
+                            self.input.accession_number(),
+                            self.input.cik(),
+                        );
+                    }
+                }
+            }
+            Err(err) => {
+                eprintln!("Failed to create SEC user client: {err}");
+            }
+        }
+    }
+}
+
+impl State for DocumentRetrieval {
+    type InputData = DocumentRetrievalInputData;
+    type OutputData = DocumentRetrievalOutputData;
+    type Context = RetrievalContext;
+
+    fn get_state_name(&self) -> impl ToString {
+        Self::STATE_NAME
+    }
+
+    fn get_input_data(&self) -> &DocumentRetrievalInputData {
+        &self.input
+    }
+
+    fn compute_output_data(&mut self) {
+        let url = self.target_url();
+        self.retrieve_from(&url);
+    }
+
+    fn get_output_data(&self) -> Option<&DocumentRetrievalOutputData> {
+        self.output.as_ref()
+    }
+
+    fn get_context_data(&self) -> &RetrievalContext {
+        &self.context
+    }
+}
+
+impl fmt::Display for DocumentRetrieval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "`{}` State Summary\n\
+             ---------------------------\n\
+             Context:\n{}\n\
+             Input Data:\n{}\n\
+             Output Data:\n{}",
+            self.get_state_name().to_string(),
+            self.context,
+            self.input,
+            self.output.as_ref().map_or_else(
+                || "\tNone".to_string(),
+                |output_data| format!("{output_data}")
+            )
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sec_state_machine::ingestion::{
+        retrieval::{
+            retrieval_context::MemoryCacheStore, retrieval_data::retrieval_input_data::CIK,
+        },
+        test_server::serve_once,
+    };
+    use std::time::Duration;
+
+    const PATH: &str = "/Archives/edgar/data/320193/000032019323000106/aapl-20230930.htm";
+
+    fn sample_document_retrieval() -> DocumentRetrieval {
+        DocumentRetrieval::new(
+            DocumentRetrievalInputData::new(
+                CIK::new("320193"),
+                "0000320193-23-000106".parse().unwrap(),
+                "aapl-20230930.htm",
+            ),
+            RetrievalContext::default(),
+        )
+    }
+
+    #[test]
+    fn should_return_name_of_document_retrieval_state_when_in_document_retrieval_state() {
+        let document_retrieval_state = sample_document_retrieval();
+
+        let expected_result = String::from("Document Retrieval");
+
+        let result = document_retrieval_state.get_state_name().to_string();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_state_name_constant_when_getting_state_name() {
+        let document_retrieval_state = sample_document_retrieval();
+
+        let expected_result = DocumentRetrieval::STATE_NAME;
+
+        let result = document_retrieval_state.get_state_name().to_string();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_build_edgar_archive_url_without_leading_zeros_and_dashes_when_calling_target_url() {
+        let document_retrieval_state = sample_document_retrieval();
+
+        let expected_result =
+            "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/aapl-20230930.htm";
+
+        let result = document_retrieval_state.target_url();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_false_when_state_has_not_computed_the_output() {
+        let document_retrieval_state = sample_document_retrieval();
+
+        let expected_result = false;
+
+        let result = document_retrieval_state.has_output_data_been_computed();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_serve_document_from_cache_when_cache_holds_target_url() {
+        let mut document_retrieval_state = sample_document_retrieval();
+        document_retrieval_state.context.update_context(
+            RetrievalContextUpdaterBuilder::new()
+                .cache(MemoryCacheStore::new(), Duration::from_mins(1))
+                .build(),
+        );
+        document_retrieval_state.context.cache().unwrap().put(
+            &document_retrieval_state.target_url(),
+            b"<html></html>",
+            SystemTime::now(),
+        );
+
+        let expected_result = Some(&DocumentRetrievalOutputData::new(b"<html></html>"));
+
+        document_retrieval_state.compute_output_data();
+        let result = document_retrieval_state.get_output_data();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_store_downloaded_document_when_server_responds_with_success_status() {
+        let mut document_retrieval_state = sample_document_retrieval();
+        let url = serve_once(
+            "HTTP/1.1 200 OK\r\nContent-Length: 13\r\n\r\n<html></html>",
+            PATH,
+        );
+
+        let expected_result = Some(&DocumentRetrievalOutputData::new(b"<html></html>"));
+
+        document_retrieval_state.retrieve_from(&url);
+        let result = document_retrieval_state.get_output_data();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_not_compute_output_when_server_responds_with_not_found() {
+        let mut document_retrieval_state = sample_document_retrieval();
+        let url = serve_once("HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n", PATH);
+
+        let expected_result = false;
+
+        document_retrieval_state.retrieve_from(&url);
+        let result = document_retrieval_state.has_output_data_been_computed();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_not_cache_truncated_document_when_content_length_is_not_verified() {
+        let mut document_retrieval_state = sample_document_retrieval();
+        document_retrieval_state.context.update_context(
+            RetrievalContextUpdaterBuilder::new()
+                .verify_content_length(false)
+                .cache(MemoryCacheStore::new(), Duration::from_mins(1))
+                .build(),
+        );
+        let url = serve_once(
+            "HTTP/1.1 200 OK\r\nContent-Length: 100\r\n\r\n<html></html>",
+            PATH,
+        );
+
+        let expected_result = None;
+
+        document_retrieval_state.retrieve_from(&url);
+        let result = document_retrieval_state
+            .get_context_data()
+            .cache()
+            .and_then(|cache| cache.get(&url));
+
+        assert_eq!(result, expected_result);
+    }
+
+    fn implements_state<T: State>() {}
+    #[test]
+    fn should_implement_state_trait_when_defined_as_document_retrieval() {
+        implements_state::<DocumentRetrieval>();
+    }
+}
@@ -0,0 +1,145 @@
+use crate::sec_state_machine::ingestion::retrieval::{
+    retrieval_context::{config::DEFAULT_CIK, get_sec_user_client},
+    retrieval_data::retrieval_input_data::CIK,
+    RetrievalContext,
+};
+use reqwest::{header::USER_AGENT, Error};
+use std::time::{Duration, Instant};
+
+/// Maximum time the health check waits for the SEC API to respond.
+pub const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Result of probing the SEC API with [`check_sec_health`].
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub enum HealthStatus {
+    /// The API answered with a success status within the timeout.
+    Reachable { latency: Duration },
+    /// The API could not be reached, timed out or answered with an error status.
+    Unreachable { reason: String },
+}
+
+impl HealthStatus {
+    #[must_use]
+    pub const fn is_reachable(&self) -> bool {
+        matches!(self, Self::Reachable { .. })
+    }
+}
+
+/// Probes whether the SEC API is reachable by requesting the (small) submissions
+/// document of the default CIK, waiting at most [`HEALTH_CHECK_TIMEOUT`].
+///
+/// The probe is reported to the context's request-log hook like any other request.
+///
+/// # Errors
+///
+/// Returns a `reqwest::Error` if the SEC user client cannot be built. Failing to reach
+/// the API is not an error but reported as [`HealthStatus::Unreachable`].
+pub fn check_sec_health(context: &RetrievalContext) -> Result<HealthStatus, Error> {
+    let url = format!(
+        "https://data.sec.gov/submissions/CIK{}.json",
+        CIK::new(DEFAULT_CIK)
+    );
+    check_health_at(&url, context, HEALTH_CHECK_TIMEOUT)
+}
+
+fn check_health_at(
+    url: &str,
+    context: &RetrievalContext,
+    timeout: Duration,
+) -> Result<HealthStatus, Error> {
+    let client = get_sec_user_client()?;
+
+    let start = Instant::now();
+    let response_result = client
+        .get(url)
+        .header(USER_AGENT, context.next_user_agent())
+        .timeout(timeout)
+        .send();
+    let latency = start.elapsed();
+
+    context.log_request(url, &response_result, latency);
+
+    Ok(match response_result {
+        Ok(response) if response.status().is_success() => HealthStatus::Reachable { latency },
+        Ok(response) => HealthStatus::Unreachable {
+            reason: format!("SEC API responded with status {}", response.status()),
+        },
+        Err(err) => HealthStatus::Unreachable {
+            reason: err.to_string(),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sec_state_machine::ingestion::test_server::serve_once;
+    use std::net::TcpListener;
+
+    const PATH: &str = "/submissions/CIK0001067983.json";
+
+    #[test]
+    fn should_report_reachable_when_api_responds_with_success_status() {
+        let url = serve_once("HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\n{}", PATH);
+
+        let expected_result = true;
+
+        let result = check_health_at(&url, &RetrievalContext::default(), HEALTH_CHECK_TIMEOUT)
+            .unwrap()
+            .is_reachable();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_report_unreachable_when_api_responds_with_error_status() {
+        let url = serve_once(
+            "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\n\r\n",
+            PATH,
+        );
+
+        let expected_result = HealthStatus::Unreachable {
+            reason: "SEC API responded with status 503 Service Unavailable".to_string(),
+        };
+
+        let result =
+            check_health_at(&url, &RetrievalContext::default(), HEALTH_CHECK_TIMEOUT).unwrap();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_report_unreachable_when_connection_is_refused() {
+        let address = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let url = format!("http://{address}{PATH}");
+
+        let expected_result = false;
+
+        let result = check_health_at(&url, &RetrievalContext::default(), HEALTH_CHECK_TIMEOUT)
+            .unwrap()
+            .is_reachable();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_report_unreachable_when_api_does_not_respond_within_timeout() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}{PATH}", listener.local_addr().unwrap());
+
+        let expected_result = false;
+
+        let result = check_health_at(
+            &url,
+            &RetrievalContext::default(),
+            Duration::from_millis(100),
+        )
+        .unwrap()
+        .is_reachable();
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -1 +1,8 @@
+pub mod daily_index;
+pub mod document_retrieval;
+pub mod health_check;
 pub mod retrieval;
+pub mod state_id;
+
+#[cfg(test)]
+mod test_server;
@@ -1,7 +1,14 @@
+use crate::sec_state_machine::ingestion::state_id::StateId;
+use reqwest::header::USER_AGENT;
 use retrieval_context::{get_sec_user_client, RetrievalContextUpdaterBuilder};
+use retrieval_data::retrieval_input_data::CIK;
 use retrieval_data::retrieval_output_data::RetrievalOutputDataUpdaterBuilder;
 use state_maschine::prelude::*;
-use std::fmt;
+use std::{
+    fmt, io,
+    num::NonZeroUsize,
+    time::{Instant, SystemTime},
+};
 
 pub mod retrieval_context;
 pub mod retrieval_data;
@@ -16,35 +23,103 @@ pub struct Retrieval {
     context: RetrievalContext,
 }
 
-impl State for Retrieval {
-    type InputData = RetrievalInputData;
-    type OutputData = RetrievalOutputData;
-    type Context = RetrievalContext;
-
-    fn get_state_name(&self) -> impl ToString {
-        "Retrieval"
+impl Retrieval {
+    /// The name returned by `get_state_name`.
+    pub const STATE_NAME: &'static str = "Retrieval";
+    /// The typed identifier of this state.
+    pub const STATE_ID: StateId = StateId::Retrieval;
+
+    #[must_use]
+    pub const fn new(input: RetrievalInputData, context: RetrievalContext) -> Self {
+        Self {
+            input,
+            output: None,
+            context,
+        }
     }
 
-    fn get_input_data(&self) -> &RetrievalInputData {
-        &self.input
+    /// Fans out a list of CIKs into one `Retrieval` state per CIK.
+    ///
+    /// Every state starts from a copy of `context` whose CIK is set to the CIK of its input,
+    /// so the resulting states can be computed independently (e.g. in parallel). An empty
+    /// list yields an empty fan-out.
+    #[must_use]
+    pub fn fan_out(ciks: &[CIK], context: &RetrievalContext) -> Vec<Self> {
+        ciks.iter()
+            .map(|cik| {
+                let mut cik_context = context.clone();
+                cik_context.update_context(
+                    RetrievalContextUpdaterBuilder::new()
+                        .status(retrieval_context::Status::PreRetrieval)
+                        .cik(cik)
+                        .build(),
+                );
+
+                Self::new(RetrievalInputData::new(cik), cik_context)
+            })
+            .collect()
+    }
+
+    /// Returns the URL of the SEC endpoint this state queries when computing its output.
+    ///
+    /// No request is made, which makes it useful for showing what would be fetched before running.
+    #[must_use]
+    pub fn target_url(&self) -> String {
+        let cik = self.get_input_data().cik();
+        format!("https://data.sec.gov/api/xbrl/companyfacts/CIK{cik}.json")
     }
 
+    /// Queries `url`, or serves the response from the cache, and stores it as the output.
     #[allow(clippy::redundant_closure)]
-    fn compute_output_data(&mut self) {
-        let cik = self.get_input_data().cik();
-        let url = format!("https://data.sec.gov/api/xbrl/companyfacts/CIK{cik}.json");
+    fn retrieve_from(&mut self, url: &str) {
+        // Serve the response from the cache without sending a request, if it holds a live entry
+        if let Some(cached) = self.context.cache().and_then(|cache| cache.get(url)) {
+            let context_updater = RetrievalContextUpdaterBuilder::new()
+                .status(retrieval_context::Status::PostRetrieval)
+                .build();
+
+            self.context.update_context(context_updater);
+
+            let output_updater = RetrievalOutputDataUpdaterBuilder::new()
+                .response(&String::from_utf8_lossy(&cached.body))
+                .fetched_at(cached.fetched_at)
+                .build();
+
+            self.output
+                .get_or_insert_with(|| RetrievalOutputData::default())
+                .update_state(output_updater);
+            return;
+        }
 
         let client_result = get_sec_user_client();
         match client_result {
             Ok(client) => {
-                let response_result = client.get(&url).send();
+                let start = Instant::now();
+                let response_result = client
+                    .get(url)
+                    .header(USER_AGENT, self.context.next_user_agent())
+                    .send();
+                let fetched_at = SystemTime::now();
+
+                self.context
+                    .log_request(url, &response_result, start.elapsed());
+
                 match response_result {
                     Ok(response) => {
-                        let response_body_result = response.text();
+                        let content_length = response.content_length();
+                        let is_success = response.status().is_success();
+                        let response_body_result = self.context.read_body(response);
 
                         match response_body_result {
-                            Ok(body) => {
-                                let response_string = body;
+                            Ok(Some(body)) => {
+                                // Never cache a truncated body, which is only accepted if verification is off
+                                if let Some(cache) = self.context.cache().filter(|_| {
+                                    is_success && !is_truncated(content_length, body.len())
+                                }) {
+                                    cache.put(url, &body, fetched_at);
+                                }
+
+                                let response_string = String::from_utf8_lossy(&body).into_owned();
 
                                 let context_updater = RetrievalContextUpdaterBuilder::new()
                                     .status(retrieval_context::Status::PostRetrieval)
@@ -54,14 +129,32 @@ impl State for Retrieval {
 
                                 let output_updater = RetrievalOutputDataUpdaterBuilder::new()
                                     .response(&response_string)
+                                    .fetched_at(fetched_at)
                                     .build();
 
                                 self.output
                                     .get_or_insert_with(|| RetrievalOutputData::default())
                                     .update_state(output_updater);
                             }
+                            Ok(None) => {
+                                eprintln!(
+                                    "Response too large for request for CIK '{}': body exceeds the limit of {} bytes.",
+                                    self.context.cik(),
+                                    self.context.max_body_bytes().unwrap_or_default(),
+                                );
+                            }
+                            Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => {
+                                eprintln!(
+                                    "Truncated response for request for CIK '{}': expected {} bytes but the body ended early.",
+                                    self.context.cik(),
+                                    content_length.unwrap_or_default(),
+                                );
+                            }
                             Err(err) => {
-                                eprintln!("Failed to convert response body of query for CIK '{}' to string: {err}", self.context.cik());
+                                eprintln!(
+                                    "Failed to read response body of query for CIK '{}': {err}",
+                                    self.context.cik()
+                                );
                             }
                         }
                     }
@@ -78,6 +171,36 @@ impl State for Retrieval {
             }
         }
     }
+}
+
+/// Splits a list of CIKs into chunks of at most `requests_per_second` CIKs, so a scheduler can
+/// release one chunk per second without exceeding the rate limit.
+///
+/// Every chunk but the last holds exactly `requests_per_second` CIKs; the order of the CIKs is kept.
+#[must_use]
+pub fn chunk_for_rate_limit(ciks: &[CIK], requests_per_second: NonZeroUsize) -> Vec<Vec<CIK>> {
+    ciks.chunks(requests_per_second.get())
+        .map(<[CIK]>::to_vec)
+        .collect()
+}
+
+impl State for Retrieval {
+    type InputData = RetrievalInputData;
+    type OutputData = RetrievalOutputData;
+    type Context = RetrievalContext;
+
+    fn get_state_name(&self) -> impl ToString {
+        Self::STATE_NAME
+    }
+
+    fn get_input_data(&self) -> &RetrievalInputData {
+        &self.input
+    }
+
+    fn compute_output_data(&mut self) {
+        let url = self.target_url();
+        self.retrieve_from(&url);
+    }
 
     fn get_output_data(&self) -> Option<&RetrievalOutputData> {
         self.output.as_ref()
@@ -88,6 +211,18 @@ impl State for Retrieval {
     }
 }
 
+/// Returns whether fewer bytes were received than the `Content-Length` header advertised.
+///
+/// Responses without a `Content-Length` header (e.g. chunked transfers) are never considered truncated.
+pub(crate) fn is_truncated(content_length: Option<u64>, received_length: usize) -> bool {
+    content_length.is_some_and(|expected_length| {
+        u64::try_from(received_length)
+            .is_ok_and(|received_length| received_length < expected_length)
+    })
+}
+
+/// Only reflects data the state already holds: formatting never sends a request, and a state whose
+/// output has not been computed shows `None` as its output data.
 impl fmt::Display for Retrieval {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -110,7 +245,17 @@ impl fmt::Display for Retrieval {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::{fmt::Debug, hash::Hash};
+    use crate::sec_state_machine::ingestion::test_server::serve_once;
+    use retrieval_context::MemoryCacheStore;
+    use std::{
+        fmt::Debug,
+        hash::Hash,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    const PATH: &str = "/api/xbrl/companyfacts/CIK0001067983.json";
+    const TRUNCATED_RESPONSE: &str = "HTTP/1.1 200 OK\r\nContent-Length: 100\r\n\r\n{\"cik\":1}";
 
     #[test]
     fn should_return_name_of_retrieval_state_when_in_retrieval_state() {
@@ -123,6 +268,26 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
+    #[test]
+    fn should_return_state_name_constant_when_getting_state_name() {
+        let retrieval_state = Retrieval::default();
+
+        let expected_result = Retrieval::STATE_NAME;
+
+        let result = retrieval_state.get_state_name().to_string();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_name_state_id_after_state_name_constant() {
+        let expected_result = Retrieval::STATE_NAME;
+
+        let result = Retrieval::STATE_ID.to_string();
+
+        assert_eq!(result, expected_result);
+    }
+
     #[test]
     fn should_return_default_retrieval_data_struct_as_input_data_when_in_initial_retrieval_state() {
         let retrieval_state = Retrieval::default();
@@ -242,7 +407,7 @@ mod tests {
     fn implements_default<T: Default>() {}
     #[test]
     fn should_implement_default_when_implementing_state_trait() {
-        implements_default::<Retrieval>()
+        implements_default::<Retrieval>();
     }
 
     fn implements_debug<T: Debug>() {}
@@ -341,6 +506,346 @@ mod tests {
         retrieval_state.compute_output_data();
         let result = retrieval_state.get_input_data();
 
-        assert_eq!(result, expected_result)
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_one_retrieval_state_per_cik_when_fanning_out_cik_list() {
+        let ciks = [CIK::new("320193"), CIK::new("789019"), CIK::new("1067983")];
+        let context = RetrievalContext::default();
+
+        let expected_result = ciks.len();
+
+        let result = Retrieval::fan_out(&ciks, &context).len();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_set_matching_input_and_context_cik_when_fanning_out_cik_list() {
+        let ciks = [CIK::new("320193"), CIK::new("789019")];
+        let context = RetrievalContext::default();
+
+        let expected_result = vec![
+            ("0000320193".to_string(), "0000320193".to_string()),
+            ("0000789019".to_string(), "0000789019".to_string()),
+        ];
+
+        let result: Vec<(String, String)> = Retrieval::fan_out(&ciks, &context)
+            .iter()
+            .map(|state| {
+                (
+                    state.get_input_data().cik().clone(),
+                    state.get_context_data().cik().clone(),
+                )
+            })
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_empty_fan_out_when_cik_list_is_empty() {
+        let context = RetrievalContext::default();
+
+        let result = Retrieval::fan_out(&[], &context);
+
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn should_return_companyfacts_url_for_input_cik_when_calling_target_url() {
+        let retrieval_state = Retrieval::new(
+            RetrievalInputData::new("320193"),
+            RetrievalContext::default(),
+        );
+
+        let expected_result = "https://data.sec.gov/api/xbrl/companyfacts/CIK0000320193.json";
+
+        let result = retrieval_state.target_url();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_invoke_request_log_hook_with_url_and_status_when_retrieving_output() {
+        let logged_requests = Arc::new(Mutex::new(Vec::new()));
+        let hook_requests = Arc::clone(&logged_requests);
+        let mut context = RetrievalContext::default();
+        context.update_context(
+            RetrievalContextUpdaterBuilder::new()
+                .on_request(move |log| {
+                    hook_requests
+                        .lock()
+                        .unwrap()
+                        .push((log.run_id, log.method, log.url, log.status));
+                })
+                .build(),
+        );
+        let mut retrieval_state = Retrieval::new(RetrievalInputData::default(), context);
+        let url = serve_once("HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\n{}", PATH);
+
+        let expected_result = vec![(
+            *retrieval_state.get_context_data().run_id(),
+            "GET".to_string(),
+            url.clone(),
+            Some(200),
+        )];
+
+        retrieval_state.retrieve_from(&url);
+        let result = logged_requests.lock().unwrap().clone();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_show_none_as_output_data_when_displaying_fresh_retrieval_state() {
+        let retrieval_state = Retrieval::default();
+
+        let expected_result = true;
+
+        let result = retrieval_state
+            .to_string()
+            .ends_with("Output Data:\n\tNone");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_not_send_request_when_displaying_fresh_retrieval_state() {
+        let request_count = Arc::new(Mutex::new(0));
+        let hook_count = Arc::clone(&request_count);
+        let mut context = RetrievalContext::default();
+        context.update_context(
+            RetrievalContextUpdaterBuilder::new()
+                .on_request(move |_| *hook_count.lock().unwrap() += 1)
+                .build(),
+        );
+        let retrieval_state = Retrieval::new(RetrievalInputData::default(), context);
+
+        let expected_result = (0, false);
+
+        let _ = retrieval_state.to_string();
+        let result = (
+            *request_count.lock().unwrap(),
+            retrieval_state.has_output_data_been_computed(),
+        );
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_serve_response_from_cache_without_request_when_cache_holds_target_url() {
+        let request_count = Arc::new(Mutex::new(0));
+        let hook_count = Arc::clone(&request_count);
+        let fetched_at = SystemTime::UNIX_EPOCH + Duration::from_hours(1);
+        let mut context = RetrievalContext::default();
+        context.update_context(
+            RetrievalContextUpdaterBuilder::new()
+                .on_request(move |_| *hook_count.lock().unwrap() += 1)
+                .cache(MemoryCacheStore::new(), Duration::from_mins(1))
+                .build(),
+        );
+        context.cache().unwrap().put(
+            &Retrieval::default().target_url(),
+            b"{\"cik\":1067983}",
+            fetched_at,
+        );
+        let mut retrieval_state = Retrieval::new(RetrievalInputData::default(), context);
+
+        let expected_result = (Some(("{\"cik\":1067983}".to_string(), Some(fetched_at))), 0);
+
+        retrieval_state.compute_output_data();
+        let result = (
+            retrieval_state
+                .get_output_data()
+                .map(|output| (output.response().clone(), output.fetched_at())),
+            *request_count.lock().unwrap(),
+        );
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_detect_truncation_when_body_is_shorter_than_content_length() {
+        let expected_result = true;
+
+        let result = is_truncated(Some(1024), 512);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_not_detect_truncation_when_body_matches_content_length() {
+        let expected_result = false;
+
+        let result = is_truncated(Some(1024), 1024);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_not_detect_truncation_when_content_length_is_missing() {
+        let expected_result = false;
+
+        let result = is_truncated(None, 512);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_not_compute_output_when_body_is_shorter_than_content_length() {
+        let mut retrieval_state = Retrieval::default();
+        let url = serve_once(TRUNCATED_RESPONSE, PATH);
+
+        let expected_result = false;
+
+        retrieval_state.retrieve_from(&url);
+        let result = retrieval_state.has_output_data_been_computed();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_keep_received_bytes_when_body_is_truncated_and_content_length_is_not_verified() {
+        let mut context = RetrievalContext::default();
+        context.update_context(
+            RetrievalContextUpdaterBuilder::new()
+                .verify_content_length(false)
+                .build(),
+        );
+        let mut retrieval_state = Retrieval::new(RetrievalInputData::default(), context);
+        let url = serve_once(TRUNCATED_RESPONSE, PATH);
+
+        let expected_result = Some("{\"cik\":1}".to_string());
+
+        retrieval_state.retrieve_from(&url);
+        let result = retrieval_state
+            .get_output_data()
+            .map(|output| output.response().clone());
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_not_cache_truncated_body_when_content_length_is_not_verified() {
+        let mut context = RetrievalContext::default();
+        context.update_context(
+            RetrievalContextUpdaterBuilder::new()
+                .verify_content_length(false)
+                .cache(MemoryCacheStore::new(), Duration::from_mins(1))
+                .build(),
+        );
+        let mut retrieval_state = Retrieval::new(RetrievalInputData::default(), context);
+        let url = serve_once(TRUNCATED_RESPONSE, PATH);
+
+        let expected_result = None;
+
+        retrieval_state.retrieve_from(&url);
+        let result = retrieval_state
+            .get_context_data()
+            .cache()
+            .and_then(|cache| cache.get(&url));
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_neither_compute_output_nor_cache_when_body_exceeds_max_body_bytes() {
+        let mut context = RetrievalContext::default();
+        context.update_context(
+            RetrievalContextUpdaterBuilder::new()
+                .max_body_bytes(16)
+                .cache(MemoryCacheStore::new(), Duration::from_mins(1))
+                .build(),
+        );
+        let mut retrieval_state = Retrieval::new(RetrievalInputData::default(), context);
+        let url = serve_once(
+            "HTTP/1.1 200 OK\r\nContent-Length: 32\r\n\r\n{\"cik\":1,\"padding\":\"xxxxxxxxxx\"}",
+            PATH,
+        );
+
+        let expected_result = (false, None);
+
+        retrieval_state.retrieve_from(&url);
+        let result = (
+            retrieval_state.has_output_data_been_computed(),
+            retrieval_state
+                .get_context_data()
+                .cache()
+                .and_then(|cache| cache.get(&url)),
+        );
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_set_fetched_at_to_time_of_request_when_computing_output() {
+        let mut retrieval_state = Retrieval::default();
+        let url = serve_once(
+            "HTTP/1.1 200 OK\r\nContent-Length: 9\r\n\r\n{\"cik\":1}",
+            PATH,
+        );
+        let before = SystemTime::now();
+
+        let expected_result = true;
+
+        retrieval_state.retrieve_from(&url);
+        let after = SystemTime::now();
+        let result = retrieval_state
+            .get_output_data()
+            .and_then(RetrievalOutputData::fetched_at)
+            .is_some_and(|fetched_at| before <= fetched_at && fetched_at <= after);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_carry_same_run_id_on_every_state_when_fanning_out_cik_list() {
+        let ciks = [CIK::new("320193"), CIK::new("789019")];
+        let context = RetrievalContext::default();
+
+        let expected_result = vec![*context.run_id(); ciks.len()];
+
+        let result: Vec<_> = Retrieval::fan_out(&ciks, &context)
+            .iter()
+            .map(|state| *state.get_context_data().run_id())
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_split_ciks_into_chunks_of_configured_rate_when_chunking_for_rate_limit() {
+        let ciks: Vec<CIK> = (1..=25).map(|cik| CIK::new(&cik)).collect();
+
+        let expected_result = vec![10, 10, 5];
+
+        let result: Vec<usize> = chunk_for_rate_limit(&ciks, NonZeroUsize::new(10).unwrap())
+            .iter()
+            .map(Vec::len)
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_keep_order_of_ciks_when_chunking_for_rate_limit() {
+        let ciks = vec![CIK::new("1"), CIK::new("2"), CIK::new("3")];
+
+        let expected_result = vec![vec![CIK::new("1"), CIK::new("2")], vec![CIK::new("3")]];
+
+        let result = chunk_for_rate_limit(&ciks, NonZeroUsize::new(2).unwrap());
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_no_chunks_when_chunking_empty_cik_list_for_rate_limit() {
+        let expected_result: Vec<Vec<CIK>> = Vec::new();
+
+        let result = chunk_for_rate_limit(&[], NonZeroUsize::new(10).unwrap());
+
+        assert_eq!(result, expected_result);
     }
 }
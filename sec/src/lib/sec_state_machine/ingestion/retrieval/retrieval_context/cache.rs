@@ -0,0 +1,394 @@
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fmt, fs,
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+use uuid::Uuid;
+
+/// Storage backend for cached responses of the SEC API, keyed by request URL.
+///
+/// Caching is best effort: a store that cannot read an entry reports a miss, and a store that cannot
+/// write an entry drops it. Implement this trait to back the cache with e.g. Redis.
+pub trait CacheStore: Send + Sync {
+    /// Returns the value stored under `key`, or `None` if there is none or it has expired.
+    fn get(&self, key: &str) -> Option<Vec<u8>>;
+
+    /// Stores `value` under `key` for `ttl`, replacing any previous value.
+    fn put(&self, key: &str, value: &[u8], ttl: Duration);
+}
+
+/// A response served from a [`ResponseCache`], together with the time it was originally fetched.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub struct CachedResponse {
+    pub body: Vec<u8>,
+    pub fetched_at: SystemTime,
+}
+
+/// A [`CacheStore`] together with the time-to-live of the entries written to it.
+///
+/// Every value is stored with a first line holding the time its response was fetched, as nanoseconds
+/// since the Unix epoch, so a cache hit reports when the data was actually retrieved.
+///
+/// Caches do not take part in comparisons or hashing: any two caches are considered equal, so
+/// configuring one does not change the identity of the context holding it.
+#[derive(Clone)]
+pub struct ResponseCache {
+    store: Arc<dyn CacheStore>,
+    ttl: Duration,
+}
+
+impl ResponseCache {
+    pub fn new(store: impl CacheStore + 'static, ttl: Duration) -> Self {
+        Self {
+            store: Arc::new(store),
+            ttl,
+        }
+    }
+
+    #[must_use]
+    pub const fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Returns the response cached under `key`, or `None` if there is none, it has expired or it
+    /// cannot be decoded.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<CachedResponse> {
+        let value = self.store.get(key)?;
+        let separator = value.iter().position(|&byte| byte == b'\n')?;
+        let fetched_at: u64 = std::str::from_utf8(&value[..separator])
+            .ok()?
+            .parse()
+            .ok()?;
+
+        Some(CachedResponse {
+            body: value[separator + 1..].to_vec(),
+            fetched_at: UNIX_EPOCH.checked_add(Duration::from_nanos(fetched_at))?,
+        })
+    }
+
+    /// Caches `body` under `key`, recording that it was fetched at `fetched_at`.
+    pub fn put(&self, key: &str, body: &[u8], fetched_at: SystemTime) {
+        let fetched_at = fetched_at
+            .duration_since(UNIX_EPOCH)
+            .ok()
+            .and_then(|fetched_at| u64::try_from(fetched_at.as_nanos()).ok())
+            .unwrap_or_default();
+
+        let mut value = format!("{fetched_at}\n").into_bytes();
+        value.extend_from_slice(body);
+        self.store.put(key, &value, self.ttl);
+    }
+}
+
+impl fmt::Debug for ResponseCache {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ResponseCache")
+            .field("ttl", &self.ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for ResponseCache {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ResponseCache {}
+
+impl PartialOrd for ResponseCache {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ResponseCache {
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Hash for ResponseCache {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+/// A cached value and the instant it expires at, or `None` if it never expires.
+type MemoryCacheEntry = (Vec<u8>, Option<Instant>);
+
+/// A [`CacheStore`] keeping its entries in memory for the lifetime of the process.
+///
+/// Expired entries are dropped whenever a new entry is written, so memory stays bounded by the live entries.
+#[derive(Debug, Default)]
+pub struct MemoryCacheStore {
+    entries: Mutex<HashMap<String, MemoryCacheEntry>>,
+}
+
+impl MemoryCacheStore {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CacheStore for MemoryCacheStore {
+    fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let (value, expires_at) = self.entries.lock().ok()?.get(key)?.clone();
+        expires_at
+            .is_none_or(|expires_at| Instant::now() < expires_at)
+            .then_some(value)
+    }
+
+    fn put(&self, key: &str, value: &[u8], ttl: Duration) {
+        if let Ok(mut entries) = self.entries.lock() {
+            let now = Instant::now();
+            entries
+                .retain(|_, (_, expires_at)| expires_at.is_none_or(|expires_at| now < expires_at));
+            entries.insert(
+                key.to_string(),
+                (value.to_vec(), Instant::now().checked_add(ttl)),
+            );
+        }
+    }
+}
+
+/// A [`CacheStore`] keeping one file per entry in a directory, so the cache survives restarts.
+///
+/// Each file starts with a line holding the expiry as seconds since the Unix epoch, followed by the value.
+/// File names are derived from the key by percent-encoding every byte other than ASCII letters, digits,
+/// `.` and `-`, so distinct keys always map to distinct files. Entries are written to a temporary file
+/// first and then renamed into place, so a reader never sees a partially written entry.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub struct FileSystemCacheStore {
+    dir: PathBuf,
+}
+
+impl FileSystemCacheStore {
+    /// Creates a store writing to `dir`; the directory is created on the first write.
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path_for(&self, key: &str) -> PathBuf {
+        let file_name: String = key
+            .bytes()
+            .map(|byte| {
+                if byte.is_ascii_alphanumeric() || byte == b'.' || byte == b'-' {
+                    char::from(byte).to_string()
+                } else {
+                    format!("%{byte:02X}")
+                }
+            })
+            .collect();
+        self.dir.join(file_name)
+    }
+}
+
+impl CacheStore for FileSystemCacheStore {
+    fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let contents = fs::read(self.path_for(key)).ok()?;
+        let separator = contents.iter().position(|&byte| byte == b'\n')?;
+        let expires_at: u64 = std::str::from_utf8(&contents[..separator])
+            .ok()?
+            .parse()
+            .ok()?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+
+        (now < expires_at).then(|| contents[separator + 1..].to_vec())
+    }
+
+    fn put(&self, key: &str, value: &[u8], ttl: Duration) {
+        let expires_at = SystemTime::now()
+            .checked_add(ttl)
+            .and_then(|expires_at| expires_at.duration_since(UNIX_EPOCH).ok())
+            .map_or(u64::MAX, |expires_at| expires_at.as_secs());
+
+        let mut contents = format!("{expires_at}\n").into_bytes();
+        contents.extend_from_slice(value);
+
+        // `_` is always percent-encoded in entry file names, so the temporary file cannot clash with an entry
+        let temp_path = self.dir.join(format!("_tmp-{}", Uuid::new_v4()));
+        if let Err(err) = fs::create_dir_all(&self.dir)
+            .and_then(|()| fs::write(&temp_path, contents))
+            .and_then(|()| fs::rename(&temp_path, self.path_for(key)))
+        {
+            let _ = fs::remove_file(&temp_path);
+            eprintln!(
+                "Failed to write cache entry for '{key}' to '{}': {err}",
+                self.dir.display()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CacheStore, CachedResponse, FileSystemCacheStore, MemoryCacheStore, ResponseCache,
+    };
+    use std::{
+        env, fs,
+        time::{Duration, SystemTime},
+    };
+    use uuid::Uuid;
+
+    const KEY: &str = "https://data.sec.gov/api/xbrl/companyfacts/CIK0001067983.json";
+
+    #[test]
+    fn should_return_stored_value_when_getting_key_from_memory_store() {
+        let store = MemoryCacheStore::new();
+
+        let expected_result = Some(b"{}".to_vec());
+
+        store.put(KEY, b"{}", Duration::from_mins(1));
+        let result = store.get(KEY);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_none_when_memory_store_entry_has_expired() {
+        let store = MemoryCacheStore::new();
+
+        let expected_result = None;
+
+        store.put(KEY, b"{}", Duration::ZERO);
+        let result = store.get(KEY);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_drop_expired_entries_when_putting_into_memory_store() {
+        let store = MemoryCacheStore::new();
+
+        let expected_result = 1;
+
+        store.put(KEY, b"{}", Duration::ZERO);
+        store.put("https://example.com", b"{}", Duration::from_mins(1));
+        let result = store.entries.lock().unwrap().len();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_leave_only_entry_file_when_putting_into_file_system_store() {
+        let dir = env::temp_dir().join(format!("sec-cache-{}", Uuid::new_v4()));
+        let store = FileSystemCacheStore::new(&dir);
+
+        let expected_result = vec![store.path_for(KEY)];
+
+        store.put(KEY, b"{}", Duration::from_mins(1));
+        let result: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_stored_value_when_getting_key_from_file_system_store() {
+        let dir = env::temp_dir().join(format!("sec-cache-{}", Uuid::new_v4()));
+        let store = FileSystemCacheStore::new(&dir);
+
+        let expected_result = Some(b"{\"cik\":1067983}".to_vec());
+
+        store.put(KEY, b"{\"cik\":1067983}", Duration::from_mins(1));
+        let result = FileSystemCacheStore::new(&dir).get(KEY);
+
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_none_when_file_system_store_entry_has_expired() {
+        let dir = env::temp_dir().join(format!("sec-cache-{}", Uuid::new_v4()));
+        let store = FileSystemCacheStore::new(&dir);
+
+        let expected_result = None;
+
+        store.put(KEY, b"{}", Duration::ZERO);
+        let result = store.get(KEY);
+
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_none_when_file_system_store_has_no_entry() {
+        let dir = env::temp_dir().join(format!("sec-cache-{}", Uuid::new_v4()));
+        let store = FileSystemCacheStore::new(dir);
+
+        let expected_result = None;
+
+        let result = store.get(KEY);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_keep_entries_apart_when_keys_differ_only_in_special_chars() {
+        let dir = env::temp_dir().join(format!("sec-cache-{}", Uuid::new_v4()));
+        let store = FileSystemCacheStore::new(&dir);
+
+        let expected_result = (Some(b"first".to_vec()), Some(b"second".to_vec()));
+
+        store.put(
+            "https://example.com/a/b_c",
+            b"first",
+            Duration::from_mins(1),
+        );
+        store.put(
+            "https://example.com/a/b/c",
+            b"second",
+            Duration::from_mins(1),
+        );
+        let result = (
+            store.get("https://example.com/a/b_c"),
+            store.get("https://example.com/a/b/c"),
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_body_and_fetch_time_when_getting_key_from_response_cache() {
+        let cache = ResponseCache::new(MemoryCacheStore::new(), Duration::from_mins(1));
+        let fetched_at = SystemTime::now();
+
+        let expected_result = Some(CachedResponse {
+            body: b"{}".to_vec(),
+            fetched_at,
+        });
+
+        cache.put(KEY, b"{}", fetched_at);
+        let result = cache.get(KEY);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_treat_different_caches_as_equal_when_comparing() {
+        let first_cache = ResponseCache::new(MemoryCacheStore::new(), Duration::from_mins(1));
+        let second_cache = ResponseCache::new(
+            FileSystemCacheStore::new(env::temp_dir()),
+            Duration::from_hours(1),
+        );
+
+        assert_eq!(first_cache, second_cache);
+    }
+}
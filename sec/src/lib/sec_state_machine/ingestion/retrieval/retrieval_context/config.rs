@@ -1,8 +1,115 @@
+use crate::sec_state_machine::ingestion::retrieval::retrieval_data::retrieval_input_data::InvalidCikFormat;
 use reqwest::{self, blocking::Client, Error};
+use std::{fmt, time::Duration};
 
 const USER_AGENT: &str = "Demir Catovic d.catovic9@gmail.com";
 pub const DEFAULT_CIK: &str = "1067983"; // BRK
-                                         //pub const DEFAULT_CIK: &str = "00001067983"; // invalid CIK (used for testing purposes)
+                                         //pub const DEFAULT_CIK: &str = "10679831067983"; // invalid CIK (used for testing purposes)
+
+/// Environment variable holding the user agent sent to the SEC API. Required by [`RetrievalContext::from_env`](super::RetrievalContext::from_env).
+pub const USER_AGENT_ENV_VAR: &str = "SEC_USER_AGENT";
+/// Environment variable holding the CIK of the context. Optional, defaults to [`DEFAULT_CIK`].
+pub const CIK_ENV_VAR: &str = "SEC_CIK";
+/// Environment variable turning the `Content-Length` check of response bodies on (`true`) or off (`false`).
+/// Optional, defaults to `true`.
+pub const VERIFY_CONTENT_LENGTH_ENV_VAR: &str = "SEC_VERIFY_CONTENT_LENGTH";
+/// Environment variable holding the directory responses are cached in. Optional, responses are not cached if unset.
+pub const CACHE_DIR_ENV_VAR: &str = "SEC_CACHE_DIR";
+/// Environment variable holding the time-to-live of cached responses in seconds. Optional, defaults to
+/// [`DEFAULT_CACHE_TTL`]; only used together with [`CACHE_DIR_ENV_VAR`].
+pub const CACHE_TTL_SECS_ENV_VAR: &str = "SEC_CACHE_TTL_SECS";
+/// Time-to-live of cached responses if [`CACHE_TTL_SECS_ENV_VAR`] is not set.
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_hours(24);
+
+/// Error returned when a user agent cannot be sent as an HTTP header value.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub struct InvalidUserAgent {
+    user_agent: String,
+    character: char,
+}
+
+impl InvalidUserAgent {
+    /// Returns the first character of the user agent that is not allowed in a header value.
+    #[must_use]
+    pub const fn character(&self) -> char {
+        self.character
+    }
+}
+
+impl fmt::Display for InvalidUserAgent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Invalid user agent: {:?} is not a printable ASCII character and cannot be sent in an HTTP header. Got: {:?}",
+            self.character, self.user_agent
+        )
+    }
+}
+
+impl std::error::Error for InvalidUserAgent {}
+
+/// Error returned when a `RetrievalContext` cannot be configured from environment variables.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub enum EnvConfigError {
+    /// A required variable is not set or empty.
+    MissingVar { name: &'static str },
+    /// The user agent variable is not a valid HTTP header value.
+    InvalidUserAgent(InvalidUserAgent),
+    /// The CIK variable is not a valid CIK.
+    InvalidCik(InvalidCikFormat),
+    /// A variable is set to a value it cannot take.
+    InvalidValue { name: &'static str, value: String },
+}
+
+impl fmt::Display for EnvConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::MissingVar { name } => write!(
+                f,
+                "Invalid environment configuration: required variable '{name}' is not set."
+            ),
+            Self::InvalidUserAgent(err) => write!(
+                f,
+                "Invalid environment configuration: '{USER_AGENT_ENV_VAR}': {err}"
+            ),
+            Self::InvalidCik(err) => {
+                write!(f, "Invalid environment configuration: '{CIK_ENV_VAR}': {err}")
+            }
+            Self::InvalidValue { name, value } => write!(
+                f,
+                "Invalid environment configuration: '{name}' must be 'true' or 'false'. Got: '{value}'"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EnvConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidUserAgent(err) => Some(err),
+            Self::InvalidCik(err) => Some(err),
+            Self::MissingVar { .. } | Self::InvalidValue { .. } => None,
+        }
+    }
+}
+
+/// Checks that a user agent only consists of printable ASCII characters (or tabs), i.e. that it is a
+/// valid HTTP header value.
+///
+/// # Errors
+///
+/// Returns an [`InvalidUserAgent`] holding the first offending character, e.g. a newline or a non-ASCII letter.
+pub fn validate_user_agent(user_agent: &str) -> Result<(), InvalidUserAgent> {
+    user_agent
+        .chars()
+        .find(|&c| !(c == '\t' || matches!(c, ' '..='~')))
+        .map_or(Ok(()), |character| {
+            Err(InvalidUserAgent {
+                user_agent: user_agent.to_string(),
+                character,
+            })
+        })
+}
 
 /// Creates a new synchronous(!) SEC user client with a custom user agent.
 ///
@@ -37,4 +144,35 @@ mod tests {
 
         assert_eq!(result, expected_user_agent);
     }
+
+    #[test]
+    fn should_accept_default_user_agent_when_validating_user_agent() {
+        let expected_result = Ok(());
+
+        let result = validate_user_agent(&get_sec_user_agent().to_string());
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_offending_newline_when_validating_user_agent_containing_newline() {
+        let expected_result = '\n';
+
+        let result = validate_user_agent("Demir Catovic\nd.catovic9@gmail.com")
+            .expect_err("Validation should fail.")
+            .character();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_offending_char_when_validating_user_agent_containing_non_ascii_char() {
+        let expected_result = 'ć';
+
+        let result = validate_user_agent("Demir Catović d.catovic9@gmail.com")
+            .expect_err("Validation should fail.")
+            .character();
+
+        assert_eq!(result, expected_result);
+    }
 }
@@ -1,16 +1,75 @@
+use crate::sec_state_machine::ingestion::retrieval::retrieval_data::retrieval_input_data::CIK;
+use reqwest::blocking::Response;
 use state_maschine::prelude::*;
-use std::fmt;
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt,
+    hash::{Hash, Hasher},
+    io::{self, Read},
+    iter,
+    time::Duration,
+};
+use uuid::Uuid;
 
+pub mod cache;
 pub mod config;
+pub mod request_log;
+pub mod user_agent_pool;
 
-pub use config::get_sec_user_client;
-use config::{get_sec_user_agent, DEFAULT_CIK};
+pub use cache::{
+    CacheStore, CachedResponse, FileSystemCacheStore, MemoryCacheStore, ResponseCache,
+};
+use config::{
+    get_sec_user_agent, validate_user_agent, CACHE_DIR_ENV_VAR, CACHE_TTL_SECS_ENV_VAR,
+    CIK_ENV_VAR, DEFAULT_CACHE_TTL, DEFAULT_CIK, USER_AGENT_ENV_VAR, VERIFY_CONTENT_LENGTH_ENV_VAR,
+};
+pub use config::{get_sec_user_client, EnvConfigError, InvalidUserAgent};
+pub use request_log::{RequestLog, RequestLogHook};
+pub use user_agent_pool::UserAgentPool;
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
 pub struct RetrievalContext {
     status: Status,
     user_agent: String,
+    user_agent_pool: Option<UserAgentPool>,
     cik: String,
+    run_id: RunId,
+    on_request: Option<RequestLogHook>,
+    verify_content_length: bool,
+    max_body_bytes: Option<u64>,
+    cache: Option<ResponseCache>,
+}
+
+/// The ID correlating all states and requests of one pipeline run.
+///
+/// Run IDs do not take part in comparisons or hashing: every context gets a fresh ID, so contexts that
+/// are configured alike compare equal regardless of the run they belong to.
+#[derive(Debug, Clone, Copy)]
+struct RunId(Uuid);
+
+impl PartialEq for RunId {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for RunId {}
+
+impl PartialOrd for RunId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RunId {
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Hash for RunId {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
@@ -39,16 +98,106 @@ impl fmt::Display for Status {
 }
 
 impl RetrievalContext {
+    /// Creates a new `RetrievalContext`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the user agent is not a valid HTTP header value.
+    /// Use [`RetrievalContext::try_new`] to handle an invalid user agent without panicking.
     pub fn new(
         status: Status,
         user_agent: &(impl ToString + ?Sized),
         cik: &(impl ToString + ?Sized),
     ) -> Self {
-        Self {
+        Self::try_new(status, user_agent, cik).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Creates a new `RetrievalContext`, validating the user agent up front.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidUserAgent`] if the user agent contains characters that cannot be sent in an
+    /// HTTP header (e.g. a newline or non-ASCII characters).
+    pub fn try_new(
+        status: Status,
+        user_agent: &(impl ToString + ?Sized),
+        cik: &(impl ToString + ?Sized),
+    ) -> Result<Self, InvalidUserAgent> {
+        let user_agent = user_agent.to_string();
+        validate_user_agent(&user_agent)?;
+
+        Ok(Self {
             status,
-            user_agent: user_agent.to_string(),
+            user_agent,
+            user_agent_pool: None,
             cik: cik.to_string(),
-        }
+            run_id: RunId(Uuid::new_v4()),
+            on_request: None,
+            verify_content_length: true,
+            max_body_bytes: None,
+            cache: None,
+        })
+    }
+
+    /// Creates a new `RetrievalContext` in the `PreRetrieval` status from environment variables:
+    ///
+    /// - `SEC_USER_AGENT` (required): user agent sent to the SEC API.
+    /// - `SEC_CIK` (optional): CIK of the context, zero-padded to ten digits. Defaults to [`DEFAULT_CIK`].
+    /// - `SEC_VERIFY_CONTENT_LENGTH` (optional): `true` or `false`. Defaults to `true`.
+    /// - `SEC_CACHE_DIR` (optional): directory to cache responses in with a [`FileSystemCacheStore`].
+    ///   Responses are not cached if unset.
+    /// - `SEC_CACHE_TTL_SECS` (optional): time-to-live of cached responses in seconds. Defaults to
+    ///   [`DEFAULT_CACHE_TTL`](config::DEFAULT_CACHE_TTL); ignored without `SEC_CACHE_DIR`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnvConfigError`] if `SEC_USER_AGENT` is not set or if any variable holds an invalid value.
+    pub fn from_env() -> Result<Self, EnvConfigError> {
+        Self::from_lookup(|name| std::env::var(name).ok())
+    }
+
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Self, EnvConfigError> {
+        let lookup = |name| lookup(name).filter(|value| !value.trim().is_empty());
+
+        let user_agent = lookup(USER_AGENT_ENV_VAR).ok_or(EnvConfigError::MissingVar {
+            name: USER_AGENT_ENV_VAR,
+        })?;
+        let cik = lookup(CIK_ENV_VAR).map_or_else(
+            || Ok(DEFAULT_CIK.to_string()),
+            |cik| {
+                CIK::try_new(&cik)
+                    .map(|cik| cik.to_string())
+                    .map_err(EnvConfigError::InvalidCik)
+            },
+        )?;
+        let verify_content_length =
+            lookup(VERIFY_CONTENT_LENGTH_ENV_VAR).map_or(Ok(true), |value| {
+                value
+                    .trim()
+                    .parse::<bool>()
+                    .map_err(|_| EnvConfigError::InvalidValue {
+                        name: VERIFY_CONTENT_LENGTH_ENV_VAR,
+                        value,
+                    })
+            })?;
+        let cache_ttl = lookup(CACHE_TTL_SECS_ENV_VAR).map_or(Ok(DEFAULT_CACHE_TTL), |value| {
+            value
+                .trim()
+                .parse::<u64>()
+                .map(Duration::from_secs)
+                .map_err(|_| EnvConfigError::InvalidValue {
+                    name: CACHE_TTL_SECS_ENV_VAR,
+                    value,
+                })
+        })?;
+        let cache = lookup(CACHE_DIR_ENV_VAR)
+            .map(|cache_dir| ResponseCache::new(FileSystemCacheStore::new(cache_dir), cache_ttl));
+
+        let mut context = Self::try_new(Status::PreRetrieval, &user_agent, &cik)
+            .map_err(EnvConfigError::InvalidUserAgent)?;
+        context.verify_content_length = verify_content_length;
+        context.cache = cache;
+        Ok(context)
     }
 
     #[must_use]
@@ -65,6 +214,116 @@ impl RetrievalContext {
     pub const fn user_agent(&self) -> &String {
         &self.user_agent
     }
+
+    /// Returns the user agents rotated through per request, if a pool is configured.
+    #[must_use]
+    pub const fn user_agent_pool(&self) -> Option<&UserAgentPool> {
+        self.user_agent_pool.as_ref()
+    }
+
+    /// Returns the user agent to send with the next request.
+    ///
+    /// With a non-empty [`UserAgentPool`] configured, every call hands out the next agent of the pool
+    /// in turn; otherwise it is always [`RetrievalContext::user_agent`].
+    #[must_use]
+    pub fn next_user_agent(&self) -> &String {
+        self.user_agent_pool
+            .as_ref()
+            .and_then(UserAgentPool::next_user_agent)
+            .unwrap_or(&self.user_agent)
+    }
+
+    /// Returns the ID correlating all states and requests of one pipeline run.
+    ///
+    /// Every context created with [`RetrievalContext::new`] or `default` starts a run with a fresh ID;
+    /// clones keep it, so the states fanned out from one context (see
+    /// [`Retrieval::fan_out`](crate::sec_state_machine::ingestion::retrieval::Retrieval::fan_out)) share it.
+    #[must_use]
+    pub const fn run_id(&self) -> &Uuid {
+        &self.run_id.0
+    }
+
+    /// Returns the hook invoked after every request sent to the SEC API, if one is registered.
+    #[must_use]
+    pub const fn on_request(&self) -> Option<&RequestLogHook> {
+        self.on_request.as_ref()
+    }
+
+    /// Passes a [`RequestLog`] of a finished `GET` request to the registered request-log hook, if any.
+    pub fn log_request(
+        &self,
+        url: &str,
+        response_result: &reqwest::Result<Response>,
+        duration: Duration,
+    ) {
+        if let Some(hook) = &self.on_request {
+            hook.log(RequestLog {
+                run_id: self.run_id.0,
+                method: "GET".to_string(),
+                url: url.to_string(),
+                status: response_result.as_ref().map_or_else(
+                    |err| err.status().map(|status| status.as_u16()),
+                    |response| Some(response.status().as_u16()),
+                ),
+                duration,
+            });
+        }
+    }
+
+    /// Returns whether the length of a response body is checked against its `Content-Length` header.
+    #[must_use]
+    pub const fn verify_content_length(&self) -> bool {
+        self.verify_content_length
+    }
+
+    /// Returns the maximum number of bytes read from a response body, or `None` if bodies are read in full.
+    #[must_use]
+    pub const fn max_body_bytes(&self) -> Option<u64> {
+        self.max_body_bytes
+    }
+
+    /// Reads a response body, stopping as soon as it exceeds [`RetrievalContext::max_body_bytes`].
+    ///
+    /// Returns `Ok(None)` if the body is larger than the limit; at most one byte more than the limit is read.
+    /// A body that ends before its `Content-Length` is an error if [`RetrievalContext::verify_content_length`]
+    /// is set; otherwise the bytes received up to that point are returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `io::Error` if reading the body fails. A truncated body is reported with
+    /// `io::ErrorKind::UnexpectedEof`.
+    pub fn read_body(&self, reader: impl Read) -> io::Result<Option<Vec<u8>>> {
+        let mut body = Vec::new();
+        if let Err(err) = reader
+            .take(
+                self.max_body_bytes
+                    .map_or(u64::MAX, |max_body_bytes| max_body_bytes.saturating_add(1)),
+            )
+            .read_to_end(&mut body)
+        {
+            // reqwest reports a body cut short as a generic body error wrapping an `UnexpectedEof`
+            if !ends_early(&err) {
+                return Err(err);
+            }
+            if self.verify_content_length {
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, err));
+            }
+        }
+
+        let exceeds_limit = self.max_body_bytes.is_some_and(|max_body_bytes| {
+            u64::try_from(body.len()).map_or(true, |length| length > max_body_bytes)
+        });
+        if exceeds_limit {
+            return Ok(None);
+        }
+        Ok(Some(body))
+    }
+
+    /// Returns the cache responses are looked up in before, and stored in after, a request, if one is configured.
+    #[must_use]
+    pub const fn cache(&self) -> Option<&ResponseCache> {
+        self.cache.as_ref()
+    }
 }
 
 impl Default for RetrievalContext {
@@ -73,12 +332,28 @@ impl Default for RetrievalContext {
     }
 }
 
+/// Returns whether `err` or any error it wraps is an `UnexpectedEof`, i.e. the body ended before its
+/// advertised length.
+fn ends_early(err: &io::Error) -> bool {
+    // `io::Error::source` skips the error an `io::Error` wraps, so step into it with `get_ref` instead
+    iter::successors(Some(err as &(dyn Error + 'static)), |&err| {
+        err.downcast_ref::<io::Error>().map_or_else(
+            || err.source(),
+            |err| err.get_ref().map(|inner| inner as &(dyn Error + 'static)),
+        )
+    })
+    .any(|err| {
+        err.downcast_ref::<io::Error>()
+            .is_some_and(|err| err.kind() == io::ErrorKind::UnexpectedEof)
+    })
+}
+
 impl fmt::Display for RetrievalContext {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "\tState: {}\n\tUser Agent: {}\n\tCIK: {}",
-            self.status, self.user_agent, self.cik
+            "\tState: {}\n\tUser Agent: {}\n\tCIK: {}\n\tRun ID: {}",
+            self.status, self.user_agent, self.cik, self.run_id.0
         )
     }
 }
@@ -94,11 +369,33 @@ impl ContextData for RetrievalContext {
             self.status = value;
         }
         if let Some(value) = updates.user_agent {
-            self.user_agent = value;
+            // Keep the current user agent rather than store one that fails every request
+            match validate_user_agent(&value) {
+                Ok(()) => self.user_agent = value,
+                Err(err) => eprintln!("Ignoring user agent update: {err}"),
+            }
+        }
+        if let Some(value) = updates.user_agent_pool {
+            self.user_agent_pool = Some(value);
         }
         if let Some(value) = updates.cik {
             self.cik = value;
         }
+        if let Some(value) = updates.run_id {
+            self.run_id = RunId(value);
+        }
+        if let Some(value) = updates.on_request {
+            self.on_request = Some(value);
+        }
+        if let Some(value) = updates.verify_content_length {
+            self.verify_content_length = value;
+        }
+        if let Some(value) = updates.max_body_bytes {
+            self.max_body_bytes = Some(value);
+        }
+        if let Some(value) = updates.cache {
+            self.cache = Some(value);
+        }
     }
 }
 
@@ -106,13 +403,25 @@ impl ContextData for RetrievalContext {
 pub struct RetrievalContextUpdater {
     pub status: Option<Status>,
     pub user_agent: Option<String>,
+    pub user_agent_pool: Option<UserAgentPool>,
     pub cik: Option<String>,
+    pub run_id: Option<Uuid>,
+    pub on_request: Option<RequestLogHook>,
+    pub verify_content_length: Option<bool>,
+    pub max_body_bytes: Option<u64>,
+    pub cache: Option<ResponseCache>,
 }
 
 pub struct RetrievalContextUpdaterBuilder {
     status: Option<Status>,
     user_agent: Option<String>,
+    user_agent_pool: Option<UserAgentPool>,
     cik: Option<String>,
+    run_id: Option<Uuid>,
+    on_request: Option<RequestLogHook>,
+    verify_content_length: Option<bool>,
+    max_body_bytes: Option<u64>,
+    cache: Option<ResponseCache>,
 }
 
 impl RetrievalContextUpdaterBuilder {
@@ -121,7 +430,13 @@ impl RetrievalContextUpdaterBuilder {
         Self {
             status: None,
             user_agent: None,
+            user_agent_pool: None,
             cik: None,
+            run_id: None,
+            on_request: None,
+            verify_content_length: None,
+            max_body_bytes: None,
+            cache: None,
         }
     }
 
@@ -131,24 +446,70 @@ impl RetrievalContextUpdaterBuilder {
         self
     }
 
+    /// Sets the user agent; an agent that cannot be sent in an HTTP header is rejected (and reported)
+    /// when the update is applied, keeping the current one.
     #[must_use]
     pub fn user_agent(mut self, user_agent: &(impl ToString + ?Sized)) -> Self {
         self.user_agent = Some(user_agent.to_string());
         self
     }
 
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn user_agent_pool(mut self, user_agent_pool: UserAgentPool) -> Self {
+        self.user_agent_pool = Some(user_agent_pool);
+        self
+    }
+
     #[must_use]
     pub fn cik(mut self, cik: &(impl ToString + ?Sized)) -> Self {
         self.cik = Some(cik.to_string());
         self
     }
 
+    #[must_use]
+    pub const fn run_id(mut self, run_id: Uuid) -> Self {
+        self.run_id = Some(run_id);
+        self
+    }
+
+    #[must_use]
+    pub fn on_request(mut self, hook: impl Fn(RequestLog) + Send + Sync + 'static) -> Self {
+        self.on_request = Some(RequestLogHook::new(hook));
+        self
+    }
+
+    #[must_use]
+    pub const fn verify_content_length(mut self, verify_content_length: bool) -> Self {
+        self.verify_content_length = Some(verify_content_length);
+        self
+    }
+
+    #[must_use]
+    pub const fn max_body_bytes(mut self, max_body_bytes: u64) -> Self {
+        self.max_body_bytes = Some(max_body_bytes);
+        self
+    }
+
+    /// Caches responses in `store`, keeping each entry for `ttl`.
+    #[must_use]
+    pub fn cache(mut self, store: impl CacheStore + 'static, ttl: Duration) -> Self {
+        self.cache = Some(ResponseCache::new(store, ttl));
+        self
+    }
+
     #[must_use]
     pub fn build(self) -> RetrievalContextUpdater {
         RetrievalContextUpdater {
             status: self.status,
             user_agent: self.user_agent,
+            user_agent_pool: self.user_agent_pool,
             cik: self.cik,
+            run_id: self.run_id,
+            on_request: self.on_request,
+            verify_content_length: self.verify_content_length,
+            max_body_bytes: self.max_body_bytes,
+            cache: self.cache,
         }
     }
 }
@@ -162,11 +523,21 @@ impl Default for RetrievalContextUpdaterBuilder {
 #[cfg(test)]
 mod tests {
     use crate::sec_state_machine::ingestion::retrieval::retrieval_context::config::{
-        get_sec_user_agent, DEFAULT_CIK,
+        get_sec_user_agent, DEFAULT_CACHE_TTL, DEFAULT_CIK,
     };
 
-    use super::{RetrievalContext, RetrievalContextUpdaterBuilder, Status};
+    use super::{
+        EnvConfigError, ResponseCache, RetrievalContext, RetrievalContextUpdaterBuilder, Status,
+        UserAgentPool,
+    };
     use state_maschine::prelude::*;
+    use std::{
+        collections::HashMap,
+        env, fs,
+        io::{self, Cursor, Read},
+        time::{Duration, SystemTime},
+    };
+    use uuid::Uuid;
 
     #[test]
     fn should_return_reference_to_default_retrieval_context_when_initialized_with_default() {
@@ -260,6 +631,21 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
+    #[test]
+    fn should_keep_user_agent_when_update_contains_user_agent_with_line_break() {
+        let mut context = RetrievalContext::default();
+        let user_agent_update = RetrievalContextUpdaterBuilder::new()
+            .user_agent("updated_user_agent@example.com\r\nX-Injected: true")
+            .build();
+
+        let expected_result = &RetrievalContext::default().user_agent().clone();
+
+        context.update_context(user_agent_update);
+        let result = context.get_context().user_agent();
+
+        assert_eq!(result, expected_result);
+    }
+
     #[test]
     fn should_change_user_agent_when_update_contains_new_user_agent() {
         let mut context = RetrievalContext::default();
@@ -287,4 +673,392 @@ mod tests {
 
         assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn should_return_user_agent_for_every_request_when_no_pool_is_configured() {
+        let context = RetrievalContext::default();
+
+        let expected_result = vec![context.user_agent().clone(); 2];
+
+        let result = vec![
+            context.next_user_agent().clone(),
+            context.next_user_agent().clone(),
+        ];
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_alternate_user_agents_across_consecutive_requests_when_pool_has_two_agents() {
+        let mut context = RetrievalContext::default();
+        let update = RetrievalContextUpdaterBuilder::new()
+            .user_agent_pool(UserAgentPool::new(&[
+                "first@example.com",
+                "second@example.com",
+            ]))
+            .build();
+
+        let expected_result = vec![
+            "first@example.com",
+            "second@example.com",
+            "first@example.com",
+        ];
+
+        context.update_context(update);
+        let result: Vec<String> = (0..3).map(|_| context.next_user_agent().clone()).collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_register_request_log_hook_when_update_contains_hook() {
+        let mut context = RetrievalContext::default();
+        let hook_update = RetrievalContextUpdaterBuilder::new()
+            .on_request(|_| {})
+            .build();
+
+        let expected_result = true;
+
+        context.update_context(hook_update);
+        let result = context.get_context().on_request().is_some();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_disable_content_length_verification_when_update_turns_it_off() {
+        let mut context = RetrievalContext::default();
+        let update = RetrievalContextUpdaterBuilder::new()
+            .verify_content_length(false)
+            .build();
+
+        let expected_result = false;
+
+        context.update_context(update);
+        let result = context.get_context().verify_content_length();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_read_whole_body_when_no_max_body_bytes_is_set() {
+        let context = RetrievalContext::default();
+
+        let expected_result = Some(vec![b'x'; 1024]);
+
+        let result = context.read_body(Cursor::new(vec![b'x'; 1024])).unwrap();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_read_body_when_body_size_equals_max_body_bytes() {
+        let mut context = RetrievalContext::default();
+        let update = RetrievalContextUpdaterBuilder::new()
+            .max_body_bytes(16)
+            .build();
+
+        let expected_result = Some(vec![b'x'; 16]);
+
+        context.update_context(update);
+        let result = context.read_body(Cursor::new(vec![b'x'; 16])).unwrap();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_none_when_body_is_larger_than_max_body_bytes() {
+        let mut context = RetrievalContext::default();
+        let update = RetrievalContextUpdaterBuilder::new()
+            .max_body_bytes(16)
+            .build();
+
+        let expected_result = None;
+
+        context.update_context(update);
+        let result = context
+            .read_body(Cursor::new(vec![b'x'; 1024 * 1024]))
+            .unwrap();
+
+        assert_eq!(result, expected_result);
+    }
+
+    /// A reader that fails like a response body whose connection closed before `Content-Length` was reached.
+    struct EndsEarly;
+
+    impl Read for EndsEarly {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "incomplete body",
+            )))
+        }
+    }
+
+    #[test]
+    fn should_return_unexpected_eof_error_when_body_ends_early_and_content_length_is_verified() {
+        let context = RetrievalContext::default();
+
+        let expected_result = io::ErrorKind::UnexpectedEof;
+
+        let result = context
+            .read_body(Cursor::new(b"{}".to_vec()).chain(EndsEarly))
+            .unwrap_err()
+            .kind();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_received_bytes_when_body_ends_early_and_content_length_is_not_verified() {
+        let mut context = RetrievalContext::default();
+        let update = RetrievalContextUpdaterBuilder::new()
+            .verify_content_length(false)
+            .build();
+
+        let expected_result = Some(b"{}".to_vec());
+
+        context.update_context(update);
+        let result = context
+            .read_body(Cursor::new(b"{}".to_vec()).chain(EndsEarly))
+            .unwrap();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_start_new_run_when_creating_another_default_context() {
+        let first_context = RetrievalContext::default();
+        let second_context = RetrievalContext::default();
+
+        let expected_result = first_context.run_id();
+
+        let result = second_context.run_id();
+
+        assert_ne!(result, expected_result);
+    }
+
+    #[test]
+    fn should_keep_run_id_when_cloning_context() {
+        let context = RetrievalContext::default();
+
+        let expected_result = context.run_id();
+
+        let cloned_context = context.clone();
+        let result = cloned_context.run_id();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_update_run_id_when_update_contains_new_run_id() {
+        let mut context = RetrievalContext::default();
+        let run_id = Uuid::new_v4();
+        let update = RetrievalContextUpdaterBuilder::new().run_id(run_id).build();
+
+        let expected_result = &run_id;
+
+        context.update_context(update);
+        let result = context.get_context().run_id();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_create_context_when_using_try_new_with_valid_user_agent() {
+        let result = RetrievalContext::try_new(
+            Status::PreRetrieval,
+            "custom_user_agent@example.com",
+            DEFAULT_CIK,
+        );
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_return_error_when_using_try_new_with_user_agent_containing_line_break() {
+        let expected_result = '\r';
+
+        let result = RetrievalContext::try_new(
+            Status::PreRetrieval,
+            "custom_user_agent@example.com\r\nX-Injected: true",
+            DEFAULT_CIK,
+        )
+        .expect_err("Creating the context should fail.")
+        .character();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid user agent")]
+    fn should_panic_when_using_new_with_user_agent_containing_newline() {
+        let _result = RetrievalContext::new(
+            Status::PreRetrieval,
+            "custom_user_agent@example.com\n",
+            DEFAULT_CIK,
+        );
+    }
+
+    fn lookup_in(env: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let env: HashMap<String, String> = env
+            .iter()
+            .map(|(name, value)| ((*name).to_string(), (*value).to_string()))
+            .collect();
+        move |name| env.get(name).cloned()
+    }
+
+    #[test]
+    fn should_configure_context_from_env_when_all_variables_are_set() {
+        let lookup = lookup_in(&[
+            ("SEC_USER_AGENT", "custom_user_agent@example.com"),
+            ("SEC_CIK", "320193"),
+            ("SEC_VERIFY_CONTENT_LENGTH", "false"),
+        ]);
+
+        let expected_result = (
+            "custom_user_agent@example.com".to_string(),
+            "0000320193".to_string(),
+            false,
+        );
+
+        let context = RetrievalContext::from_lookup(lookup).unwrap();
+        let result = (
+            context.user_agent().clone(),
+            context.cik().clone(),
+            context.verify_content_length(),
+        );
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fall_back_to_defaults_when_optional_env_variables_are_missing() {
+        let lookup = lookup_in(&[("SEC_USER_AGENT", "custom_user_agent@example.com")]);
+
+        let expected_result = &RetrievalContext::new(
+            Status::PreRetrieval,
+            "custom_user_agent@example.com",
+            DEFAULT_CIK,
+        );
+
+        let result = &RetrievalContext::from_lookup(lookup).unwrap();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_missing_var_error_when_user_agent_env_variable_is_missing() {
+        let lookup = lookup_in(&[("SEC_CIK", "320193")]);
+
+        let expected_result = Err(EnvConfigError::MissingVar {
+            name: "SEC_USER_AGENT",
+        });
+
+        let result = RetrievalContext::from_lookup(lookup);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_invalid_value_error_when_verify_content_length_env_variable_is_not_a_bool() {
+        let lookup = lookup_in(&[
+            ("SEC_USER_AGENT", "custom_user_agent@example.com"),
+            ("SEC_VERIFY_CONTENT_LENGTH", "yes"),
+        ]);
+
+        let expected_result = Err(EnvConfigError::InvalidValue {
+            name: "SEC_VERIFY_CONTENT_LENGTH",
+            value: "yes".to_string(),
+        });
+
+        let result = RetrievalContext::from_lookup(lookup);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_not_cache_responses_when_cache_dir_env_variable_is_missing() {
+        let lookup = lookup_in(&[
+            ("SEC_USER_AGENT", "custom_user_agent@example.com"),
+            ("SEC_CACHE_TTL_SECS", "600"),
+        ]);
+
+        let expected_result = true;
+
+        let result = RetrievalContext::from_lookup(lookup)
+            .unwrap()
+            .cache()
+            .is_none();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_use_cache_ttl_from_env_when_cache_dir_env_variable_is_set() {
+        let lookup = lookup_in(&[
+            ("SEC_USER_AGENT", "custom_user_agent@example.com"),
+            ("SEC_CACHE_DIR", "/tmp/sec-cache"),
+            ("SEC_CACHE_TTL_SECS", "600"),
+        ]);
+
+        let expected_result = Some(Duration::from_mins(10));
+
+        let result = RetrievalContext::from_lookup(lookup)
+            .unwrap()
+            .cache()
+            .map(ResponseCache::ttl);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_write_cache_entries_to_cache_dir_when_cache_dir_env_variable_is_set() {
+        let dir = env::temp_dir().join(format!("sec-cache-{}", Uuid::new_v4()));
+        let lookup = lookup_in(&[
+            ("SEC_USER_AGENT", "custom_user_agent@example.com"),
+            ("SEC_CACHE_DIR", dir.to_str().unwrap()),
+        ]);
+        let context = RetrievalContext::from_lookup(lookup).unwrap();
+
+        let expected_result = (true, Some(DEFAULT_CACHE_TTL));
+
+        context
+            .cache()
+            .unwrap()
+            .put("https://example.com", b"{}", SystemTime::now());
+        let result = (dir.is_dir(), context.cache().map(ResponseCache::ttl));
+
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_invalid_value_error_when_cache_ttl_env_variable_is_not_a_number() {
+        let lookup = lookup_in(&[
+            ("SEC_USER_AGENT", "custom_user_agent@example.com"),
+            ("SEC_CACHE_TTL_SECS", "1h"),
+        ]);
+
+        let expected_result = Err(EnvConfigError::InvalidValue {
+            name: "SEC_CACHE_TTL_SECS",
+            value: "1h".to_string(),
+        });
+
+        let result = RetrievalContext::from_lookup(lookup);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_invalid_cik_error_when_cik_env_variable_is_not_numeric() {
+        let lookup = lookup_in(&[
+            ("SEC_USER_AGENT", "custom_user_agent@example.com"),
+            ("SEC_CIK", "BRK"),
+        ]);
+
+        let result = RetrievalContext::from_lookup(lookup);
+
+        assert!(matches!(result, Err(EnvConfigError::InvalidCik(_))));
+    }
 }
@@ -0,0 +1,106 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    sync::Arc,
+    time::Duration,
+};
+use uuid::Uuid;
+
+/// Summary of a single request sent to the SEC API.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub struct RequestLog {
+    /// ID of the pipeline run the request belongs to.
+    pub run_id: Uuid,
+    pub method: String,
+    pub url: String,
+    /// HTTP status code of the response, or `None` if no response was received.
+    pub status: Option<u16>,
+    pub duration: Duration,
+}
+
+/// Callback invoked with a [`RequestLog`] after every request sent to the SEC API.
+///
+/// Hooks do not take part in comparisons or hashing: any two hooks are considered equal, so
+/// registering one does not change the identity of the context holding it.
+#[derive(Clone)]
+pub struct RequestLogHook(Arc<dyn Fn(RequestLog) + Send + Sync>);
+
+impl RequestLogHook {
+    pub fn new(hook: impl Fn(RequestLog) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(hook))
+    }
+
+    pub fn log(&self, request_log: RequestLog) {
+        (self.0)(request_log);
+    }
+}
+
+impl fmt::Debug for RequestLogHook {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("RequestLogHook")
+    }
+}
+
+impl PartialEq for RequestLogHook {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for RequestLogHook {}
+
+impl PartialOrd for RequestLogHook {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RequestLogHook {
+    fn cmp(&self, _other: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Hash for RequestLogHook {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RequestLog, RequestLogHook};
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+    use uuid::Uuid;
+
+    #[test]
+    fn should_pass_request_log_to_callback_when_logging_with_hook() {
+        let logs = Arc::new(Mutex::new(Vec::new()));
+        let hook_logs = Arc::clone(&logs);
+        let hook = RequestLogHook::new(move |log| hook_logs.lock().unwrap().push(log));
+        let request_log = RequestLog {
+            run_id: Uuid::new_v4(),
+            method: "GET".to_string(),
+            url: "https://data.sec.gov/api/xbrl/companyfacts/CIK0001067983.json".to_string(),
+            status: Some(200),
+            duration: Duration::from_millis(42),
+        };
+
+        let expected_result = vec![request_log.clone()];
+
+        hook.log(request_log);
+        let result = logs.lock().unwrap().clone();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_treat_different_hooks_as_equal_when_comparing() {
+        let first_hook = RequestLogHook::new(|_| {});
+        let second_hook = RequestLogHook::new(|_log| {});
+
+        assert_eq!(first_hook, second_hook);
+    }
+}
@@ -0,0 +1,132 @@
+use super::{config::validate_user_agent, InvalidUserAgent};
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    sync::{
+        atomic::{self, AtomicUsize},
+        Arc,
+    },
+};
+
+/// User agents that are handed out in turn, one per request sent to the SEC API.
+///
+/// Clones share their position in the rotation, so contexts cloned from one another (e.g. by
+/// [`Retrieval::fan_out`](crate::sec_state_machine::ingestion::retrieval::Retrieval::fan_out))
+/// keep alternating instead of all starting with the first agent. Only the agents take part in
+/// comparisons and hashing, not the position in the rotation.
+#[derive(Debug, Clone)]
+pub struct UserAgentPool {
+    user_agents: Vec<String>,
+    next: Arc<AtomicUsize>,
+}
+
+impl UserAgentPool {
+    /// Creates a new `UserAgentPool`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if any of the user agents is not a valid HTTP header value.
+    /// Use [`UserAgentPool::try_new`] to handle an invalid user agent without panicking.
+    pub fn new(user_agents: &[impl ToString]) -> Self {
+        Self::try_new(user_agents).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Creates a new `UserAgentPool`, validating every user agent up front.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidUserAgent`] for the first user agent that cannot be sent in an HTTP header.
+    pub fn try_new(user_agents: &[impl ToString]) -> Result<Self, InvalidUserAgent> {
+        let user_agents: Vec<String> = user_agents.iter().map(ToString::to_string).collect();
+        for user_agent in &user_agents {
+            validate_user_agent(user_agent)?;
+        }
+
+        Ok(Self {
+            user_agents,
+            next: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    #[must_use]
+    pub const fn user_agents(&self) -> &Vec<String> {
+        &self.user_agents
+    }
+
+    /// Returns the user agent to use for the next request and advances the rotation, or `None` if
+    /// the pool is empty.
+    #[must_use]
+    pub fn next_user_agent(&self) -> Option<&String> {
+        if self.user_agents.is_empty() {
+            return None;
+        }
+        let index = self.next.fetch_add(1, atomic::Ordering::Relaxed) % self.user_agents.len();
+        self.user_agents.get(index)
+    }
+}
+
+impl PartialEq for UserAgentPool {
+    fn eq(&self, other: &Self) -> bool {
+        self.user_agents == other.user_agents
+    }
+}
+
+impl Eq for UserAgentPool {}
+
+impl PartialOrd for UserAgentPool {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UserAgentPool {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.user_agents.cmp(&other.user_agents)
+    }
+}
+
+impl Hash for UserAgentPool {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.user_agents.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UserAgentPool;
+
+    #[test]
+    fn should_return_none_when_pool_is_empty() {
+        let pool = UserAgentPool::new(&[] as &[&str]);
+
+        let expected_result = None;
+
+        let result = pool.next_user_agent();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_continue_rotation_when_pool_has_been_cloned() {
+        let pool = UserAgentPool::new(&["first@example.com", "second@example.com"]);
+        let cloned_pool = pool.clone();
+
+        let expected_result = Some(&"second@example.com".to_string());
+
+        let _ = pool.next_user_agent();
+        let result = cloned_pool.next_user_agent();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_offending_char_when_using_try_new_with_an_invalid_user_agent() {
+        let expected_result = '\n';
+
+        let result = UserAgentPool::try_new(&["first@example.com", "second@example.com\n"])
+            .expect_err("Creating the pool should fail.")
+            .character();
+
+        assert_eq!(result, expected_result);
+    }
+}
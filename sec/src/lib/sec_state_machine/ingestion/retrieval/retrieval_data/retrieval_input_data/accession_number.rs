@@ -0,0 +1,159 @@
+use std::{fmt, str::FromStr};
+
+/// Digit counts of the filer ID, year and sequence groups of an accession number.
+const DIGIT_GROUP_LENGTHS: [usize; 3] = [10, 2, 6];
+const SEPARATOR: char = '-';
+
+/// Error returned when a string is not a valid accession number.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub struct InvalidAccessionNumber {
+    accession_number: String,
+}
+
+impl fmt::Display for InvalidAccessionNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Invalid accession number: expected 18 digits as '##########-##-######' or '##################'. Got: '{}'",
+            self.accession_number
+        )
+    }
+}
+
+impl std::error::Error for InvalidAccessionNumber {}
+
+/// The accession number uniquely identifying an SEC filing, e.g. `0000320193-23-000106`.
+///
+/// Both the dashed and the undashed form are accepted when parsing; the value is always stored in
+/// the canonical dashed form.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub struct AccessionNumber {
+    value: String,
+}
+
+impl AccessionNumber {
+    /// Returns the accession number in its canonical dashed form, e.g. `0000320193-23-000106`.
+    #[must_use]
+    pub const fn value(&self) -> &String {
+        &self.value
+    }
+
+    /// Returns the accession number without dashes, e.g. `000032019323000106`, as used in EDGAR archive paths.
+    #[must_use]
+    pub fn without_dashes(&self) -> String {
+        self.value.replace(SEPARATOR, "")
+    }
+}
+
+impl FromStr for AccessionNumber {
+    type Err = InvalidAccessionNumber;
+
+    /// Parses an accession number given as `0000320193-23-000106` or `000032019323000106`.
+    /// Surrounding whitespace is ignored.
+    fn from_str(accession_number: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidAccessionNumber {
+            accession_number: accession_number.to_string(),
+        };
+        let trimmed = accession_number.trim();
+
+        let digit_groups: Vec<&str> = if trimmed.contains(SEPARATOR) {
+            trimmed.split(SEPARATOR).collect()
+        } else if trimmed.len() == DIGIT_GROUP_LENGTHS.iter().sum() {
+            let (filer_id, rest) = trimmed
+                .split_at_checked(DIGIT_GROUP_LENGTHS[0])
+                .ok_or_else(invalid)?;
+            let (year, sequence) = rest
+                .split_at_checked(DIGIT_GROUP_LENGTHS[1])
+                .ok_or_else(invalid)?;
+            vec![filer_id, year, sequence]
+        } else {
+            return Err(invalid());
+        };
+
+        let is_valid = digit_groups.len() == DIGIT_GROUP_LENGTHS.len()
+            && digit_groups
+                .iter()
+                .zip(DIGIT_GROUP_LENGTHS)
+                .all(|(group, length)| {
+                    group.len() == length && group.chars().all(|c| c.is_ascii_digit())
+                });
+        if !is_valid {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            value: digit_groups.join(&SEPARATOR.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for AccessionNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AccessionNumber;
+
+    #[test]
+    fn should_keep_canonical_form_when_parsing_dashed_accession_number() {
+        let expected_result = "0000320193-23-000106";
+
+        let result: AccessionNumber = "0000320193-23-000106".parse().unwrap();
+
+        assert_eq!(result.value(), expected_result);
+    }
+
+    #[test]
+    fn should_normalize_to_dashed_form_when_parsing_undashed_accession_number() {
+        let expected_result = "0000320193-23-000106";
+
+        let result: AccessionNumber = "000032019323000106".parse().unwrap();
+
+        assert_eq!(result.value(), expected_result);
+    }
+
+    #[test]
+    fn should_parse_both_forms_to_equal_values_when_they_denote_same_filing() {
+        let expected_result: AccessionNumber = "0000320193-23-000106".parse().unwrap();
+
+        let result: AccessionNumber = "  000032019323000106 ".parse().unwrap();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_strip_dashes_when_calling_without_dashes() {
+        let expected_result = "000032019323000106";
+
+        let result = "0000320193-23-000106"
+            .parse::<AccessionNumber>()
+            .unwrap()
+            .without_dashes();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_error_when_digit_groups_have_wrong_lengths() {
+        let result = "000032019-323-000106".parse::<AccessionNumber>();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_return_error_when_accession_number_contains_non_numeric_chars() {
+        let result = "0000320193-AB-000106".parse::<AccessionNumber>();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_return_error_when_undashed_accession_number_has_wrong_length() {
+        let result = "00003201932300010".parse::<AccessionNumber>();
+
+        assert!(result.is_err());
+    }
+}
@@ -8,12 +8,77 @@ pub struct CIK {
     value: String,
 }
 
+/// The reason a string could not be turned into a [`CIK`].
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub enum InvalidCikFormat {
+    /// The input is empty or consists only of whitespace.
+    Empty { cik: String },
+    /// The (trimmed) input contains characters other than ASCII digits.
+    NonNumeric { cik: String },
+    /// The (trimmed) input has more significant digits than the fixed CIK length allows.
+    TooLong { cik: String, length: usize },
+}
+
+impl fmt::Display for InvalidCikFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty { cik } => write!(
+                f,
+                "Invalid CIK: CIK is empty after trimming whitespace. Got: '{cik}'"
+            ),
+            Self::NonNumeric { cik } => write!(
+                f,
+                "Invalid CIK: CIK must contain only numeric characters. Got: '{cik}'"
+            ),
+            Self::TooLong { cik, length } => write!(
+                f,
+                "Invalid CIK: CIK cannot exceed the fixed CIK length of {CIK_LENGTH} digits. Got: '{cik}' which is {length} digits long."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InvalidCikFormat {}
+
+impl InvalidCikFormat {
+    /// Returns a hint on how to fix the input, suitable for CLI and UI error messages.
+    #[must_use]
+    pub fn suggestion(&self) -> String {
+        match self {
+            Self::Empty { .. } => "Enter a CIK: the input is blank.".to_string(),
+            Self::NonNumeric { cik } if cik.trim().to_ascii_uppercase().starts_with("CIK") => {
+                "Remove the 'CIK' prefix: only the digits are the CIK, e.g. '320193'.".to_string()
+            }
+            Self::NonNumeric { cik } if cik.chars().any(char::is_alphabetic) => {
+                "Remove the letters: CIKs are numeric, e.g. '320193'.".to_string()
+            }
+            Self::NonNumeric { .. } => {
+                "Remove the separators and symbols: CIKs consist of digits only, e.g. '320193'."
+                    .to_string()
+            }
+            Self::TooLong { length, .. } => format!(
+                "Check for extra digits: CIKs have at most {CIK_LENGTH} digits, but this one has {length}."
+            ),
+        }
+    }
+}
+
+/// The outcome of validating many CIK strings at once with [`CIK::validate_batch`].
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub struct BatchValidation {
+    /// The valid CIKs, in input order.
+    pub valid: Vec<CIK>,
+    /// The invalid inputs with the reason each was rejected, in input order.
+    pub invalid: Vec<(String, InvalidCikFormat)>,
+}
+
 impl CIK {
     /// Creates a new `CIK` from a string, trimming whitespace and padding with zeros if less than 10 digits.
     ///
     /// # Panics
     ///
-    /// This function will panic if the input string contains non-numeric characters or if it's longer than 10 digits.
+    /// This function will panic if the input string is empty (after trimming), contains non-numeric characters
+    /// or if it has more than 10 significant digits. Use [`CIK::try_new`] to handle invalid input without panicking.
     ///
     /// # Examples
     ///
@@ -23,31 +88,61 @@ impl CIK {
     /// let cik = CIK::new("123456789");
     /// assert_eq!(cik.value(), "0123456789");
     /// ```
-
     pub fn new(cik: &(impl ToString + ?Sized)) -> Self {
-        let mut cik_str = cik.to_string().trim().to_string(); // Trim leading and trailing whitespace
+        Self::try_new(cik).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Creates a new `CIK` from a string, trimming whitespace and padding with zeros if less than 10 digits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidCikFormat`] if the input string is empty (after trimming), contains non-numeric
+    /// characters or if it has more than 10 significant digits. Leading zeros are stripped before the
+    /// length is checked, so over-padded input is normalized, while overlong input is rejected rather than truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sec::sec_state_machine::ingestion::retrieval::retrieval_data::retrieval_input_data::{
+    ///     InvalidCikFormat, CIK,
+    /// };
+    ///
+    /// assert!(CIK::try_new("1234567890").is_ok());
+    /// assert!(matches!(
+    ///     CIK::try_new("12345678901"),
+    ///     Err(InvalidCikFormat::TooLong { length: 11, .. })
+    /// ));
+    /// ```
+    pub fn try_new(cik: &(impl ToString + ?Sized)) -> Result<Self, InvalidCikFormat> {
+        let raw_cik = cik.to_string();
+        let cik_str = raw_cik.trim(); // Trim leading and trailing whitespace
+
+        // Reject blank input (e.g. an empty spreadsheet cell) instead of padding it to all zeros
+        if cik_str.is_empty() {
+            return Err(InvalidCikFormat::Empty { cik: raw_cik });
+        }
 
         // Check if it contains only digits
-        assert!(
-            cik_str.chars().all(|c| c.is_ascii_digit()),
-            "Invalid CIK: CIK must contain only numeric characters. Got: {}",
-            cik.to_string()
-        );
-
-        // Prepend zeros if less than 10 digits
-        if cik_str.len() < CIK_LENGTH {
-            cik_str = format!("{cik_str:0>CIK_LENGTH$}"); // Pads with leading zeros to a length of `CIK_LENGTH`
+        if !cik_str.chars().all(|c| c.is_ascii_digit()) {
+            return Err(InvalidCikFormat::NonNumeric { cik: raw_cik });
         }
 
-        // Ensure the length does not exceed `CIK_LENGTH`` digits
-        assert!(
-            (cik_str.len() <= CIK_LENGTH),
-            "Invalid CIK: Final CIK cannot exceed the fixed CIK length of {CIK_LENGTH} digits. Final CIK is '{}' and is {}>{CIK_LENGTH} digits long.",
-            cik_str.len(),
-            cik.to_string()
-        );
+        // Strip existing padding so that only significant digits count towards the length,
+        // e.g. an over-padded "0000000000001234" is the CIK "0000001234"
+        let significant_digits = cik_str.trim_start_matches('0');
+
+        // Ensure the significant digits do not exceed `CIK_LENGTH` digits
+        if significant_digits.len() > CIK_LENGTH {
+            return Err(InvalidCikFormat::TooLong {
+                length: significant_digits.len(),
+                cik: raw_cik,
+            });
+        }
 
-        Self { value: cik_str }
+        // Re-pad the significant digits with zeros to exactly 10 digits
+        Ok(Self {
+            value: format!("{significant_digits:0>CIK_LENGTH$}"), // Pads with leading zeros to a length of `CIK_LENGTH`
+        })
     }
 
     /// Returns the underlying CIK string.
@@ -56,6 +151,37 @@ impl CIK {
         &self.value
     }
 
+    /// Checks whether two CIK strings refer to the same entity, regardless of padding and surrounding whitespace.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidCikFormat`] for the first of the two inputs that is not a valid CIK.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sec::sec_state_machine::ingestion::retrieval::retrieval_data::retrieval_input_data::CIK;
+    ///
+    /// assert_eq!(CIK::same_entity("1234", "0000001234"), Ok(true));
+    /// ```
+    pub fn same_entity(a: &str, b: &str) -> Result<bool, InvalidCikFormat> {
+        Ok(Self::try_new(a)? == Self::try_new(b)?)
+    }
+
+    /// Validates many CIK strings in a single pass, e.g. a spreadsheet column, collecting both the valid
+    /// CIKs and the rejected inputs.
+    #[must_use]
+    pub fn validate_batch(raw: impl IntoIterator<Item = String>) -> BatchValidation {
+        raw.into_iter()
+            .fold(BatchValidation::default(), |mut batch, raw_cik| {
+                match Self::try_new(&raw_cik) {
+                    Ok(cik) => batch.valid.push(cik),
+                    Err(err) => batch.invalid.push((raw_cik, err)),
+                }
+                batch
+            })
+    }
+
     /// Validates if the CIK contains exactly 10 digits.
     #[must_use]
     pub fn is_valid(cik: &str) -> bool {
@@ -71,7 +197,7 @@ impl fmt::Display for CIK {
 
 #[cfg(test)]
 mod tests {
-    use super::CIK;
+    use super::{BatchValidation, InvalidCikFormat, CIK};
 
     #[test]
     fn should_create_valid_cik_struct_if_numeric_string_with_ten_digits_is_passed() {
@@ -114,14 +240,22 @@ mod tests {
     }
 
     #[test]
-    fn should_create_cik_with_all_zeros_when_passed_empty_string() {
-        let cik_str = "";
+    #[should_panic(expected = "Invalid CIK")]
+    fn should_panic_when_given_empty_cik_str() {
+        let _result = CIK::new("");
+    }
+
+    #[test]
+    fn should_return_empty_error_when_passed_whitespace_only_string() {
+        let cik_str = "   \t ";
 
-        let expected_result = "0000000000";
+        let expected_result = Err(InvalidCikFormat::Empty {
+            cik: "   \t ".to_string(),
+        });
 
-        let result = CIK::new(cik_str);
+        let result = CIK::try_new(cik_str);
 
-        assert_eq!(result.value(), expected_result);
+        assert_eq!(result, expected_result);
     }
 
     #[test]
@@ -157,4 +291,168 @@ mod tests {
 
         assert_eq!(result.value(), expected_result);
     }
+
+    #[test]
+    fn should_accept_cik_when_passed_string_with_exactly_ten_digits() {
+        let cik_str = "1234567890";
+
+        let expected_result = Ok(CIK::new("1234567890"));
+
+        let result = CIK::try_new(cik_str);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_too_long_error_when_passed_string_with_eleven_digits() {
+        let cik_str = "12345678901";
+
+        let expected_result = Err(InvalidCikFormat::TooLong {
+            cik: "12345678901".to_string(),
+            length: 11,
+        });
+
+        let result = CIK::try_new(cik_str);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_non_numeric_error_when_passed_string_with_non_numeric_chars() {
+        let cik_str = "12345abcd!";
+
+        let expected_result = Err(InvalidCikFormat::NonNumeric {
+            cik: "12345abcd!".to_string(),
+        });
+
+        let result = CIK::try_new(cik_str);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_true_when_comparing_unpadded_and_padded_form_of_same_cik() {
+        let expected_result = Ok(true);
+
+        let result = CIK::same_entity("1234", "0000001234");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_false_when_comparing_two_different_ciks() {
+        let expected_result = Ok(false);
+
+        let result = CIK::same_entity("1234", "320193");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_error_when_comparing_cik_with_invalid_input() {
+        let expected_result = Err(InvalidCikFormat::NonNumeric {
+            cik: "BRK".to_string(),
+        });
+
+        let result = CIK::same_entity("1067983", "BRK");
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_split_valid_and_invalid_ciks_when_validating_mixed_batch() {
+        let raw = ["320193", "BRK", " 1067983 ", "", "12345678901"].map(String::from);
+
+        let expected_result = BatchValidation {
+            valid: vec![CIK::new("320193"), CIK::new("1067983")],
+            invalid: vec![
+                (
+                    "BRK".to_string(),
+                    InvalidCikFormat::NonNumeric {
+                        cik: "BRK".to_string(),
+                    },
+                ),
+                (
+                    String::new(),
+                    InvalidCikFormat::Empty { cik: String::new() },
+                ),
+                (
+                    "12345678901".to_string(),
+                    InvalidCikFormat::TooLong {
+                        cik: "12345678901".to_string(),
+                        length: 11,
+                    },
+                ),
+            ],
+        };
+
+        let result = CIK::validate_batch(raw);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_suggest_removing_letters_when_cik_contains_letters() {
+        let expected_result = "Remove the letters: CIKs are numeric, e.g. '320193'.";
+
+        let result = CIK::try_new("32O193").unwrap_err().suggestion();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_suggest_removing_prefix_when_cik_starts_with_cik_prefix() {
+        let expected_result =
+            "Remove the 'CIK' prefix: only the digits are the CIK, e.g. '320193'.";
+
+        let result = CIK::try_new("CIK0000320193").unwrap_err().suggestion();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_suggest_checking_for_extra_digits_when_cik_is_too_long() {
+        let expected_result =
+            "Check for extra digits: CIKs have at most 10 digits, but this one has 11.";
+
+        let result = CIK::try_new("12345678901").unwrap_err().suggestion();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_normalize_over_padded_cik_when_passed_string_with_extra_leading_zeros() {
+        let cik_str = "0000000000001234";
+
+        let expected_result = "0000001234";
+
+        let result = CIK::new(cik_str);
+
+        assert_eq!(result.value(), expected_result);
+    }
+
+    #[test]
+    fn should_return_too_long_error_with_significant_digit_count_when_passed_padded_overlong_cik() {
+        let cik_str = "0012345678901";
+
+        let expected_result = Err(InvalidCikFormat::TooLong {
+            cik: "0012345678901".to_string(),
+            length: 11,
+        });
+
+        let result = CIK::try_new(cik_str);
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_create_all_zero_cik_when_passed_only_zeros() {
+        let cik_str = "000000000000";
+
+        let expected_result = "0000000000";
+
+        let result = CIK::new(cik_str);
+
+        assert_eq!(result.value(), expected_result);
+    }
 }
@@ -0,0 +1,146 @@
+use std::{convert::Infallible, fmt, str::FromStr};
+
+const AMENDMENT_SUFFIX: &str = "/A";
+
+/// The type of an SEC filing form, e.g. `10-K` or its amendment `10-K/A`.
+///
+/// Forms without a dedicated variant are preserved as [`FormType::Other`], so parsing never fails.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub enum FormType {
+    /// Annual report.
+    TenK { amended: bool },
+    /// Quarterly report.
+    TenQ { amended: bool },
+    /// Current report.
+    EightK { amended: bool },
+    /// Annual report of a foreign private issuer.
+    TwentyF { amended: bool },
+    /// Any other form, kept verbatim (including a possible `/A` suffix).
+    Other(String),
+}
+
+impl FormType {
+    /// Returns whether the form is an amendment of an earlier filing (`/A` suffix).
+    #[must_use]
+    pub fn is_amendment(&self) -> bool {
+        match self {
+            Self::TenK { amended }
+            | Self::TenQ { amended }
+            | Self::EightK { amended }
+            | Self::TwentyF { amended } => *amended,
+            Self::Other(form) => form.ends_with(AMENDMENT_SUFFIX),
+        }
+    }
+}
+
+impl FromStr for FormType {
+    type Err = Infallible;
+
+    /// Parses a form string such as `10-K`, `10-q` or `8-K/A`. Surrounding whitespace is ignored.
+    fn from_str(form: &str) -> Result<Self, Self::Err> {
+        let form = form.trim();
+        let normalized_form = form.to_ascii_uppercase();
+        let (base_form, amended) = normalized_form
+            .strip_suffix(AMENDMENT_SUFFIX)
+            .map_or((normalized_form.as_str(), false), |base_form| {
+                (base_form, true)
+            });
+
+        Ok(match base_form {
+            "10-K" => Self::TenK { amended },
+            "10-Q" => Self::TenQ { amended },
+            "8-K" => Self::EightK { amended },
+            "20-F" => Self::TwentyF { amended },
+            _ => Self::Other(form.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for FormType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (base_form, amended) = match self {
+            Self::TenK { amended } => ("10-K", *amended),
+            Self::TenQ { amended } => ("10-Q", *amended),
+            Self::EightK { amended } => ("8-K", *amended),
+            Self::TwentyF { amended } => ("20-F", *amended),
+            Self::Other(form) => (form.as_str(), false),
+        };
+        let suffix = if amended { AMENDMENT_SUFFIX } else { "" };
+        write!(f, "{base_form}{suffix}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FormType;
+
+    #[test]
+    fn should_parse_annual_report_when_passed_ten_k() {
+        let expected_result = FormType::TenK { amended: false };
+
+        let result: FormType = "10-K".parse().unwrap();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_parse_amended_annual_report_when_passed_ten_k_with_amendment_suffix() {
+        let expected_result = FormType::TenK { amended: true };
+
+        let result: FormType = "10-K/A".parse().unwrap();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_parse_common_forms_when_passed_their_form_strings() {
+        let expected_result = vec![
+            FormType::TenQ { amended: false },
+            FormType::EightK { amended: false },
+            FormType::TwentyF { amended: true },
+        ];
+
+        let result: Vec<FormType> = ["10-Q", "8-K", "20-F/A"]
+            .iter()
+            .map(|form| form.parse().unwrap())
+            .collect();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_ignore_case_and_whitespace_when_parsing_known_forms() {
+        let expected_result = FormType::TenQ { amended: true };
+
+        let result: FormType = "  10-q/a ".parse().unwrap();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_preserve_unknown_form_when_passed_form_without_dedicated_variant() {
+        let expected_result = FormType::Other("S-1/A".to_string());
+
+        let result: FormType = "S-1/A".parse().unwrap();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_report_amendment_when_unknown_form_has_amendment_suffix() {
+        let expected_result = true;
+
+        let result = "S-1/A".parse::<FormType>().unwrap().is_amendment();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_round_trip_form_string_when_displaying_parsed_form() {
+        let expected_result = "8-K/A";
+
+        let result = "8-K/A".parse::<FormType>().unwrap().to_string();
+
+        assert_eq!(result, expected_result);
+    }
+}
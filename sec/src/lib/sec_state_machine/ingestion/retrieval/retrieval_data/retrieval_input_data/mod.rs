@@ -2,8 +2,12 @@ use crate::sec_state_machine::ingestion::retrieval::retrieval_context::config::D
 use state_maschine::prelude::*;
 use std::fmt;
 
+pub mod accession_number;
 pub mod cik;
-pub use cik::CIK;
+pub mod form_type;
+pub use accession_number::{AccessionNumber, InvalidAccessionNumber};
+pub use cik::{BatchValidation, InvalidCikFormat, CIK};
+pub use form_type::FormType;
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
 pub struct RetrievalInputData {
@@ -30,7 +34,7 @@ impl Default for RetrievalInputData {
 
 impl fmt::Display for RetrievalInputData {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "\tCIK: {}", self.cik(),)
+        write!(f, "\tCIK: {}", self.cik())
     }
 }
 
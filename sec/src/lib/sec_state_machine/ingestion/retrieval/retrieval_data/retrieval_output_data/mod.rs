@@ -1,15 +1,17 @@
 use state_maschine::prelude::*;
-use std::fmt;
+use std::{fmt, time::SystemTime};
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
 pub struct RetrievalOutputData {
     response: String,
+    fetched_at: Option<SystemTime>,
 }
 
 impl RetrievalOutputData {
     pub fn new(response: &(impl ToString + ?Sized)) -> Self {
         Self {
             response: response.to_string(),
+            fetched_at: None,
         }
     }
 
@@ -17,6 +19,12 @@ impl RetrievalOutputData {
     pub const fn response(&self) -> &String {
         &self.response
     }
+
+    /// Returns when the response was received from the SEC API, or `None` if the data was not fetched.
+    #[must_use]
+    pub const fn fetched_at(&self) -> Option<SystemTime> {
+        self.fetched_at
+    }
 }
 
 impl Default for RetrievalOutputData {
@@ -27,7 +35,7 @@ impl Default for RetrievalOutputData {
 
 impl fmt::Display for RetrievalOutputData {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "\tResponse: {}", self.response(),)
+        write!(f, "\tResponse: {}", self.response())
     }
 }
 
@@ -41,21 +49,29 @@ impl StateData for RetrievalOutputData {
         if let Some(value) = updates.response {
             self.response = value;
         }
+        if let Some(value) = updates.fetched_at {
+            self.fetched_at = Some(value);
+        }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq, Ord)]
 pub struct RetrievalOutputDataUpdater {
     pub response: Option<String>,
+    pub fetched_at: Option<SystemTime>,
 }
 
 pub struct RetrievalOutputDataUpdaterBuilder {
     response: Option<String>,
+    fetched_at: Option<SystemTime>,
 }
 impl RetrievalOutputDataUpdaterBuilder {
     #[must_use]
     pub const fn new() -> Self {
-        Self { response: None }
+        Self {
+            response: None,
+            fetched_at: None,
+        }
     }
 
     #[must_use]
@@ -64,10 +80,17 @@ impl RetrievalOutputDataUpdaterBuilder {
         self
     }
 
+    #[must_use]
+    pub const fn fetched_at(mut self, fetched_at: SystemTime) -> Self {
+        self.fetched_at = Some(fetched_at);
+        self
+    }
+
     #[must_use]
     pub fn build(self) -> RetrievalOutputDataUpdater {
         RetrievalOutputDataUpdater {
             response: self.response,
+            fetched_at: self.fetched_at,
         }
     }
 }
@@ -82,6 +105,7 @@ impl Default for RetrievalOutputDataUpdaterBuilder {
 mod tests {
     use super::{RetrievalOutputData, RetrievalOutputDataUpdaterBuilder};
     use state_maschine::prelude::*;
+    use std::time::{Duration, SystemTime};
 
     #[test]
     fn should_return_reference_to_default_retrieval_state_data_when_initialized_with_default() {
@@ -149,6 +173,33 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
+    #[test]
+    fn should_return_no_fetch_timestamp_when_retrieval_output_data_initialized_with_default() {
+        let retrieval_state_data = &RetrievalOutputData::default();
+
+        let expected_result = None;
+
+        let result = retrieval_state_data.get_state().fetched_at();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_update_fetch_timestamp_when_update_contains_fetch_timestamp() {
+        let mut state_data = RetrievalOutputData::default();
+        let fetched_at = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let update = RetrievalOutputDataUpdaterBuilder::default()
+            .fetched_at(fetched_at)
+            .build();
+
+        let expected_result = Some(fetched_at);
+
+        state_data.update_state(update);
+        let result = state_data.get_state().fetched_at();
+
+        assert_eq!(result, expected_result);
+    }
+
     #[test]
     fn should_return_empty_string_when_retrieval_output_data_initialized_with_default() {
         let retrieval_state_data = &RetrievalOutputData::default();
@@ -0,0 +1,40 @@
+use super::{document_retrieval::DocumentRetrieval, retrieval::Retrieval};
+use std::fmt;
+
+/// Typed identifier of the ingestion states, for matching on a state without comparing names.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub enum StateId {
+    Retrieval,
+    DocumentRetrieval,
+}
+
+impl StateId {
+    /// Returns the name of the identified state, as returned by its `get_state_name`.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Retrieval => Retrieval::STATE_NAME,
+            Self::DocumentRetrieval => DocumentRetrieval::STATE_NAME,
+        }
+    }
+}
+
+impl fmt::Display for StateId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StateId;
+
+    #[test]
+    fn should_display_state_name_when_displaying_state_id() {
+        let expected_result = "Document Retrieval";
+
+        let result = StateId::DocumentRetrieval.to_string();
+
+        assert_eq!(result, expected_result);
+    }
+}
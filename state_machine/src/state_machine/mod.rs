@@ -270,7 +270,7 @@ mod tests {
     fn implements_default<T: Default>() {}
     #[test]
     fn should_implement_default_when_implementing_state_machine_trait() {
-        implements_default::<SampleState>()
+        implements_default::<SampleState>();
     }
 
     fn implements_debug<T: Debug>() {}
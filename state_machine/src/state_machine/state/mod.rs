@@ -2,9 +2,11 @@ use std::{fmt::Debug, hash::Hash};
 
 pub mod context_data;
 pub mod state_data;
+pub mod timed;
 
 pub use context_data::ContextData;
 pub use state_data::StateData;
+pub use timed::Timed;
 
 /// The `State` trait defines the behavior and characteristics of a state within a state machine.
 ///
@@ -250,7 +252,7 @@ mod tests {
     fn implements_default<T: Default>() {}
     #[test]
     fn should_implement_default_when_implementing_state_trait() {
-        implements_default::<SampleState>()
+        implements_default::<SampleState>();
     }
 
     fn implements_debug<T: Debug>() {}
@@ -361,6 +363,6 @@ mod tests {
         sample_state.compute_output_data();
         let result = sample_state.get_input_data();
 
-        assert_eq!(result, expected_result)
+        assert_eq!(result, expected_result);
     }
 }
@@ -0,0 +1,137 @@
+use crate::state_machine::state::State;
+use std::time::{Duration, Instant};
+
+/// The `Timed` decorator records how long the wrapped state took to compute its output data.
+///
+/// `Timed<S>` implements `State` itself by delegating every method to the wrapped state `S`, measuring
+/// the time spent in `compute_output_data`. This way any state can be timed without reimplementing the
+/// measurement, e.g. by using `Timed<MyState>` in place of `MyState` within a state machine.
+///
+/// # Type Parameters
+///
+/// - `S`: The state whose output data computation is timed.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd, Hash, Eq, Ord)]
+pub struct Timed<S: State> {
+    state: S,
+    last_duration: Option<Duration>,
+}
+
+impl<S: State> Timed<S> {
+    /// Wraps `state` without a recorded duration.
+    #[must_use]
+    pub const fn new(state: S) -> Self {
+        Self {
+            state,
+            last_duration: None,
+        }
+    }
+
+    /// Returns a reference to the wrapped state.
+    #[must_use]
+    pub const fn inner(&self) -> &S {
+        &self.state
+    }
+
+    /// Consumes the decorator and returns the wrapped state.
+    #[must_use]
+    pub fn into_inner(self) -> S {
+        self.state
+    }
+
+    /// Returns how long the most recent call to `compute_output_data` took, or `None` if the output
+    /// data has not been computed yet.
+    #[must_use]
+    pub const fn last_duration(&self) -> Option<Duration> {
+        self.last_duration
+    }
+}
+
+impl<S: State> State for Timed<S> {
+    type InputData = S::InputData;
+    type OutputData = S::OutputData;
+    type Context = S::Context;
+
+    fn get_state_name(&self) -> impl ToString {
+        self.state.get_state_name()
+    }
+
+    fn get_input_data(&self) -> &Self::InputData {
+        self.state.get_input_data()
+    }
+
+    fn compute_output_data(&mut self) {
+        let start = Instant::now();
+        self.state.compute_output_data();
+        self.last_duration = Some(start.elapsed());
+    }
+
+    fn get_output_data(&self) -> Option<&Self::OutputData> {
+        self.state.get_output_data()
+    }
+
+    fn has_output_data_been_computed(&self) -> bool {
+        self.state.has_output_data_been_computed()
+    }
+
+    fn get_context_data(&self) -> &Self::Context {
+        self.state.get_context_data()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::common::{SampleState, SampleStateData};
+
+    #[test]
+    fn should_return_none_when_output_data_has_not_been_computed() {
+        let timed_state = Timed::new(SampleState::default());
+
+        let expected_result = None;
+
+        let result = timed_state.last_duration();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_record_duration_when_output_data_has_been_computed() {
+        let mut timed_state = Timed::new(SampleState::default());
+
+        let expected_result = true;
+
+        timed_state.compute_output_data();
+        let result = timed_state.last_duration().is_some();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_output_data_of_wrapped_state_when_output_data_has_been_computed() {
+        let mut timed_state = Timed::new(SampleState::default());
+
+        let expected_result = Some(&SampleStateData::default());
+
+        timed_state.compute_output_data();
+        let result = timed_state.get_output_data();
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_return_name_of_wrapped_state_when_timed() {
+        let timed_state = Timed::new(SampleState::default());
+
+        let expected_result = String::from("Sample State");
+
+        let result = timed_state.get_state_name().to_string();
+
+        assert_eq!(result, expected_result);
+    }
+
+    fn implements_state<T: State>() {}
+    #[test]
+    fn should_implement_state_trait_when_wrapping_a_state() {
+        implements_state::<Timed<SampleState>>();
+    }
+}